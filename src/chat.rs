@@ -2,12 +2,18 @@ use log::{debug, info, trace, warn};
 use std::io;
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use crate::guid::{Guid, GuidKind};
 use crate::memory::ProcessMemoryReader;
-use crate::offsets;
+use crate::names::NameCache;
+use crate::offsets::{self, Offsets};
+use crate::recording::PollRecorder;
 
 static NEXT_MESSAGE_ID: AtomicU64 = AtomicU64::new(1);
 
 // ─── Message Type ───────────────────────────────────────────────────
+//
+// Numeric IDs as stored at `MessageType` in a chat slot (docs/offsets.md §3): the
+// client's CHAT_MSG_* order, which puts SYSTEM after LOOT.
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ChatMessageType {
@@ -40,6 +46,30 @@ pub enum ChatMessageType {
     Skill,
     Loot,
     System,
+    Money,
+    Opening,
+    Tradeskills,
+    PetInfo,
+    CombatMiscInfo,
+    CombatXpGain,
+    CombatHonorGain,
+    CombatFactionChange,
+    BgSystemNeutral,
+    BgSystemAlliance,
+    BgSystemHorde,
+    RaidLeader,
+    RaidWarning,
+    RaidBossEmote,
+    RaidBossWhisper,
+    Filtered,
+    Battleground,
+    BattlegroundLeader,
+    Restricted,
+    Battlenet,
+    Achievement,
+    GuildAchievement,
+    ArenaPoints,
+    PartyLeader,
     Unknown(u32),
 }
 
@@ -75,6 +105,30 @@ impl ChatMessageType {
             26 => Self::Skill,
             27 => Self::Loot,
             28 => Self::System,
+            29 => Self::Money,
+            30 => Self::Opening,
+            31 => Self::Tradeskills,
+            32 => Self::PetInfo,
+            33 => Self::CombatMiscInfo,
+            34 => Self::CombatXpGain,
+            35 => Self::CombatHonorGain,
+            36 => Self::CombatFactionChange,
+            37 => Self::BgSystemNeutral,
+            38 => Self::BgSystemAlliance,
+            39 => Self::BgSystemHorde,
+            40 => Self::RaidLeader,
+            41 => Self::RaidWarning,
+            42 => Self::RaidBossEmote,
+            43 => Self::RaidBossWhisper,
+            44 => Self::Filtered,
+            45 => Self::Battleground,
+            46 => Self::BattlegroundLeader,
+            47 => Self::Restricted,
+            48 => Self::Battlenet,
+            49 => Self::Achievement,
+            50 => Self::GuildAchievement,
+            51 => Self::ArenaPoints,
+            52 => Self::PartyLeader,
             other => Self::Unknown(other),
         }
     }
@@ -110,10 +164,48 @@ impl ChatMessageType {
             Self::Skill => "Skill",
             Self::Loot => "Loot",
             Self::System => "System",
+            Self::Money => "Money",
+            Self::Opening => "Opening",
+            Self::Tradeskills => "Tradeskill",
+            Self::PetInfo => "Pet",
+            Self::CombatMiscInfo => "Combat",
+            Self::CombatXpGain => "XP",
+            Self::CombatHonorGain => "Honor",
+            Self::CombatFactionChange => "Reputation",
+            Self::BgSystemNeutral => "Battleground",
+            Self::BgSystemAlliance => "Alliance",
+            Self::BgSystemHorde => "Horde",
+            Self::RaidLeader => "Raid Leader",
+            Self::RaidWarning => "Raid Warning",
+            Self::RaidBossEmote => "Boss Emote",
+            Self::RaidBossWhisper => "Boss Whisper",
+            Self::Filtered => "Filtered",
+            Self::Battleground => "Battleground",
+            Self::BattlegroundLeader => "Battleground Leader",
+            Self::Restricted => "Restricted",
+            Self::Battlenet => "Battle.net",
+            Self::Achievement => "Achievement",
+            Self::GuildAchievement => "Guild Achievement",
+            Self::ArenaPoints => "Arena Points",
+            Self::PartyLeader => "Party Leader",
             Self::Unknown(_) => "???",
         }
     }
 
+    /// Said by a creature rather than a player.
+    pub fn is_monster(&self) -> bool {
+        matches!(
+            self,
+            Self::MonsterSay
+                | Self::MonsterParty
+                | Self::MonsterYell
+                | Self::MonsterWhisper
+                | Self::MonsterEmote
+                | Self::RaidBossEmote
+                | Self::RaidBossWhisper
+        )
+    }
+
     pub fn color(&self) -> [f32; 4] {
         match self {
             Self::Say | Self::MonsterSay => [1.0, 1.0, 1.0, 1.0],
@@ -130,6 +222,25 @@ impl ChatMessageType {
             Self::Loot => [0.0, 0.8, 0.0, 1.0],
             Self::Skill => [0.3, 0.3, 1.0, 1.0],
             Self::Afk | Self::Dnd => [1.0, 1.0, 0.0, 1.0],
+            Self::Money | Self::Achievement => [1.0, 1.0, 0.0, 1.0],
+            Self::Tradeskills | Self::ArenaPoints => [1.0, 1.0, 1.0, 1.0],
+            Self::Opening | Self::PetInfo | Self::CombatMiscInfo | Self::CombatFactionChange => {
+                [0.5, 0.5, 1.0, 1.0]
+            }
+            Self::CombatXpGain => [0.44, 0.44, 1.0, 1.0],
+            Self::CombatHonorGain => [0.88, 0.79, 0.04, 1.0],
+            Self::BgSystemNeutral => [1.0, 0.47, 0.04, 1.0],
+            Self::BgSystemAlliance => [0.0, 0.68, 0.94, 1.0],
+            Self::BgSystemHorde => [1.0, 0.0, 0.0, 1.0],
+            Self::RaidLeader => [1.0, 0.28, 0.04, 1.0],
+            Self::RaidWarning => [1.0, 0.28, 0.0, 1.0],
+            Self::RaidBossEmote | Self::RaidBossWhisper => [1.0, 0.87, 0.0, 1.0],
+            Self::Filtered | Self::Restricted => [1.0, 0.0, 0.0, 1.0],
+            Self::Battleground => [1.0, 0.5, 0.0, 1.0],
+            Self::BattlegroundLeader => [1.0, 0.86, 0.72, 1.0],
+            Self::Battlenet => [0.0, 1.0, 0.96, 1.0],
+            Self::GuildAchievement => [0.25, 1.0, 0.25, 1.0],
+            Self::PartyLeader => [0.46, 0.78, 1.0, 1.0],
             _ => [0.7, 0.7, 0.7, 1.0],
         }
     }
@@ -137,35 +248,267 @@ impl ChatMessageType {
 
 // ─── WoW Link / Rich Text Types ─────────────────────────────────────
 
+/// A `|H<kind>:<payload>|h` hyperlink with its full 3.3.5a payload. Numeric fields
+/// follow the client's link formats; missing or malformed fields read as 0.
 #[derive(Debug, Clone)]
 pub enum WowLinkType {
-    Item(u32),
+    /// `item:id:enchant:gem1:gem2:gem3:gem4:suffix:unique:level`
+    Item {
+        id: u32,
+        enchant: u32,
+        gems: [u32; 4],
+        /// Random property ("of the Bear") if positive, random suffix if negative.
+        suffix: i32,
+        unique: i32,
+        /// Level of the character that linked the item.
+        level: u32,
+    },
     Spell(u32),
-    Achievement(u32),
-    Quest(u32),
-    Trade(u32),
-    Other,
+    /// Profession recipe, `enchant:spellId`.
+    Enchant(u32),
+    /// `talent:talentId:rank`, rank 0-based.
+    Talent { id: u32, rank: i32 },
+    /// `glyph:socket:glyphId`
+    Glyph { socket: u32, id: u32 },
+    /// `achievement:id:playerGuid:completed:month:day:year:c1:c2:c3:c4`
+    Achievement {
+        id: u32,
+        player: Guid,
+        /// Month, day and year (since 2000) it was earned.
+        completed: Option<(u32, u32, u32)>,
+        /// Criteria progress bits for an uncompleted achievement.
+        criteria: [u32; 4],
+    },
+    /// `quest:id:level`
+    Quest { id: u32, level: i32 },
+    /// `trade:spellId:rank:maxRank:playerGuid:recipes`
+    Trade {
+        spell_id: u32,
+        rank: u32,
+        max_rank: u32,
+        player: Guid,
+        /// Base64 bitmask of known recipes.
+        recipes: String,
+    },
+    /// `player:Name` (a realm may follow the name after `-`).
+    Player(String),
+    /// `channel:target`, a channel number or name.
+    Channel(String),
+    /// `instancelock:playerGuid:mapId:difficulty:defeatedMask`
+    InstanceLock {
+        player: Guid,
+        map_id: u32,
+        difficulty: u32,
+        defeated_mask: u32,
+    },
+    /// Creature entry, from the sender GUID of a monster message.
+    Npc(u32),
+    /// Any other link kind, e.g. `levelup` or `lootroll`.
+    Other(String),
 }
 
 impl WowLinkType {
+    /// Parse the payload of a `|H...|h` escape, e.g. "item:49908:0:0:...".
+    pub fn parse(data: &str) -> Self {
+        let (kind, rest) = data.split_once(':').unwrap_or((data, ""));
+        let fields: Vec<&str> = rest.split(':').collect();
+        let num = |i: usize| link_field::<u32>(&fields, i);
+        let signed = |i: usize| link_field::<i32>(&fields, i);
+        let guid = |i: usize| {
+            let hex = fields.get(i).copied().unwrap_or("");
+            let hex = hex.strip_prefix("0x").unwrap_or(hex);
+            Guid(u64::from_str_radix(hex, 16).unwrap_or(0))
+        };
+        match kind {
+            "item" => WowLinkType::Item {
+                id: num(0),
+                enchant: num(1),
+                gems: [num(2), num(3), num(4), num(5)],
+                suffix: signed(6),
+                unique: signed(7),
+                level: num(8),
+            },
+            "spell" => WowLinkType::Spell(num(0)),
+            "enchant" => WowLinkType::Enchant(num(0)),
+            "talent" => WowLinkType::Talent {
+                id: num(0),
+                rank: signed(1),
+            },
+            "glyph" => WowLinkType::Glyph {
+                socket: num(0),
+                id: num(1),
+            },
+            "achievement" => WowLinkType::Achievement {
+                id: num(0),
+                player: guid(1),
+                completed: (num(2) != 0).then(|| (num(3), num(4), num(5))),
+                criteria: [num(6), num(7), num(8), num(9)],
+            },
+            "quest" => WowLinkType::Quest {
+                id: num(0),
+                level: signed(1),
+            },
+            "trade" => WowLinkType::Trade {
+                spell_id: num(0),
+                rank: num(1),
+                max_rank: num(2),
+                player: guid(3),
+                recipes: fields.get(4).copied().unwrap_or("").to_string(),
+            },
+            "player" => WowLinkType::Player(fields[0].to_string()),
+            "channel" => WowLinkType::Channel(rest.to_string()),
+            "instancelock" => WowLinkType::InstanceLock {
+                player: guid(0),
+                map_id: num(1),
+                difficulty: num(2),
+                defeated_mask: num(3),
+            },
+            _ => WowLinkType::Other(kind.to_string()),
+        }
+    }
+
     /// Returns a Wowhead URL for this link. Known types with a valid ID get a direct link;
     /// unknown types or id=0 fall back to a search query using the display name.
     pub fn wowhead_url(&self, display_name: &str) -> String {
         match self {
-            WowLinkType::Item(id) if *id > 0 => format!("https://www.wowhead.com/item={}", id),
-            WowLinkType::Spell(id) if *id > 0 => format!("https://www.wowhead.com/spell={}", id),
-            WowLinkType::Achievement(id) if *id > 0 => {
+            WowLinkType::Item { id, .. } if *id > 0 => {
+                format!("https://www.wowhead.com/item={}", id)
+            }
+            WowLinkType::Spell(id) | WowLinkType::Enchant(id) if *id > 0 => {
+                format!("https://www.wowhead.com/spell={}", id)
+            }
+            WowLinkType::Achievement { id, .. } if *id > 0 => {
                 format!("https://www.wowhead.com/achievement={}", id)
             }
-            WowLinkType::Quest(id) if *id > 0 => {
+            WowLinkType::Quest { id, .. } if *id > 0 => {
                 format!("https://www.wowhead.com/wotlk/quest={}", id)
             }
-            WowLinkType::Trade(id) if *id > 0 => {
-                format!("https://www.wowhead.com/wotlk/skill={}", id)
+            WowLinkType::Trade { spell_id, .. } if *spell_id > 0 => {
+                format!("https://www.wowhead.com/wotlk/spell={}", spell_id)
             }
+            WowLinkType::Npc(id) if *id > 0 => format!("https://www.wowhead.com/wotlk/npc={}", id),
             _ => format!("https://www.wowhead.com/search?q={}", url_encode(display_name)),
         }
     }
+
+    /// Payload details for a tooltip, one line each. Zero fields are left out.
+    pub fn details(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        match self {
+            WowLinkType::Item {
+                id,
+                enchant,
+                gems,
+                suffix,
+                unique,
+                level,
+            } => {
+                lines.push(format!("Item {}", id));
+                if *enchant != 0 {
+                    lines.push(format!("Enchant {}", enchant));
+                }
+                let gems: Vec<String> =
+                    gems.iter().filter(|&&g| g != 0).map(|g| g.to_string()).collect();
+                if !gems.is_empty() {
+                    lines.push(format!("Gems {}", gems.join(", ")));
+                }
+                if *suffix != 0 {
+                    lines.push(format!("Random suffix {}", suffix));
+                }
+                if *unique != 0 {
+                    lines.push(format!("Unique ID {}", unique));
+                }
+                if *level != 0 {
+                    lines.push(format!("Linked at level {}", level));
+                }
+            }
+            WowLinkType::Spell(id) => lines.push(format!("Spell {}", id)),
+            WowLinkType::Enchant(id) => lines.push(format!("Recipe spell {}", id)),
+            WowLinkType::Talent { id, rank } => {
+                lines.push(format!("Talent {}", id));
+                if *rank >= 0 {
+                    lines.push(format!("Rank {}", rank + 1));
+                } else {
+                    lines.push("Not learned".to_string());
+                }
+            }
+            WowLinkType::Glyph { socket, id } => {
+                lines.push(format!("Glyph {} in socket {}", id, socket));
+            }
+            WowLinkType::Achievement {
+                id,
+                player,
+                completed,
+                criteria,
+            } => {
+                lines.push(format!("Achievement {}", id));
+                if !player.is_empty() {
+                    lines.push(format!("Player {}", player));
+                }
+                match completed {
+                    Some((month, day, year)) => lines.push(format!(
+                        "Completed {:02}/{:02}/{:02}",
+                        month, day, year
+                    )),
+                    None if criteria.iter().any(|&c| c != 0) => lines.push(format!(
+                        "Criteria {:08X} {:08X} {:08X} {:08X}",
+                        criteria[0], criteria[1], criteria[2], criteria[3]
+                    )),
+                    None => lines.push("Not completed".to_string()),
+                }
+            }
+            WowLinkType::Quest { id, level } => {
+                lines.push(format!("Quest {}", id));
+                if *level > 0 {
+                    lines.push(format!("Level {}", level));
+                }
+            }
+            WowLinkType::Trade {
+                spell_id,
+                rank,
+                max_rank,
+                player,
+                recipes,
+            } => {
+                lines.push(format!("Profession spell {}", spell_id));
+                lines.push(format!("Skill {}/{}", rank, max_rank));
+                if !player.is_empty() {
+                    lines.push(format!("Player {}", player));
+                }
+                if !recipes.is_empty() {
+                    lines.push(format!("Recipe data {} chars", recipes.len()));
+                }
+            }
+            WowLinkType::Player(name) => lines.push(format!("Player {}", name)),
+            WowLinkType::Channel(target) => lines.push(format!("Channel {}", target)),
+            WowLinkType::InstanceLock {
+                player,
+                map_id,
+                difficulty,
+                defeated_mask,
+            } => {
+                lines.push(format!("Instance lock, map {}", map_id));
+                lines.push(format!("Difficulty {}", difficulty));
+                lines.push(format!(
+                    "{} encounters defeated",
+                    defeated_mask.count_ones()
+                ));
+                if !player.is_empty() {
+                    lines.push(format!("Player {}", player));
+                }
+            }
+            WowLinkType::Npc(id) => lines.push(format!("Creature entry {}", id)),
+            WowLinkType::Other(kind) => lines.push(format!("{} link", kind)),
+        }
+        lines
+    }
+}
+
+fn link_field<T: std::str::FromStr + Default>(fields: &[&str], index: usize) -> T {
+    fields
+        .get(index)
+        .and_then(|f| f.parse().ok())
+        .unwrap_or_default()
 }
 
 /// Percent-encode a string for use in a URL query parameter.
@@ -192,15 +535,36 @@ pub enum TextSegment {
         link_type: WowLinkType,
         display_name: String,
         color: [f32; 4],
+        /// The whole link as the client wrote it, e.g.
+        /// `|cffa335ee|Hitem:49908:...|h[Primordial Saronite]|h|r`.
+        raw: String,
     },
+    /// A `|Hplayer:Name|h[Name]|h` link: clickable, with the sender menu.
+    Player {
+        name: String,
+        display_name: String,
+        color: [f32; 4],
+        raw: String,
+    },
+}
+
+impl TextSegment {
+    /// The segment as it appeared in the client's text, links included.
+    pub fn to_wow_string(&self) -> &str {
+        match self {
+            TextSegment::Plain(text) => text,
+            TextSegment::WowLink { raw, .. } | TextSegment::Player { raw, .. } => raw,
+        }
+    }
 }
 
 // ─── Chat Message ───────────────────────────────────────────────────
 
 #[derive(Debug, Clone)]
+#[allow(dead_code)]
 pub struct ChatMessage {
     pub id: u64,
-    pub sender_guid: u64,
+    pub sender_guid: Guid,
     pub sender_name: String,
     pub text: String,
     pub formatted: String,
@@ -209,6 +573,8 @@ pub struct ChatMessage {
     pub channel_name: String,
     pub timestamp: u32,
     pub segments: Vec<TextSegment>,
+    /// Character (or PID) of the client the message was read from.
+    pub source: String,
 }
 
 impl ChatMessage {
@@ -222,7 +588,7 @@ impl ChatMessage {
             return None;
         }
 
-        let sender_guid = read_u64(data, offsets::MSG_SENDER_GUID);
+        let sender_guid = Guid(read_u64(data, offsets::MSG_SENDER_GUID));
         let formatted = read_cstring(data, offsets::MSG_FORMATTED, offsets::MSG_STRING_MAX_LEN);
         let raw_text = read_cstring(data, offsets::MSG_PLAIN_TEXT, offsets::MSG_STRING_MAX_LEN);
         let text = strip_wow_formatting(&raw_text);
@@ -232,7 +598,7 @@ impl ChatMessage {
         let timestamp = read_u32(data, offsets::MSG_TIMESTAMP);
 
         trace!(
-            "  raw fields: guid=0x{:X} type_raw={} ch={} ts={} formatted_len={} text_len={}",
+            "  raw fields: guid={} type_raw={} ch={} ts={} formatted_len={} text_len={}",
             sender_guid,
             msg_type_raw,
             channel_number,
@@ -280,9 +646,34 @@ impl ChatMessage {
             channel_name,
             timestamp,
             segments,
+            source: String::new(),
         })
     }
 
+    /// System line shown in place of `count` messages overwritten before they were read.
+    /// `None` when the buffer wrapped completely and the number is unknown.
+    pub fn lost_marker(count: Option<u32>) -> Self {
+        let text = match count {
+            Some(count) => format!("{} messages lost (chat buffer wrapped between reads)", count),
+            None => "Messages may have been lost (chat buffer wrapped completely between reads)"
+                .to_string(),
+        };
+        ChatMessage {
+            id: NEXT_MESSAGE_ID.fetch_add(1, Ordering::Relaxed),
+            sender_guid: Guid::default(),
+            sender_name: String::new(),
+            text,
+            formatted: String::new(),
+            message_type: ChatMessageType::System,
+            channel_number: 0,
+            channel_name: String::new(),
+            timestamp: 0,
+            // No segments, so the marker is never sent for translation.
+            segments: Vec::new(),
+            source: String::new(),
+        }
+    }
+
     /// Type label including channel name for channel messages.
     pub fn type_label(&self) -> String {
         match self.message_type {
@@ -318,9 +709,22 @@ impl ChatMessage {
         format!("{}{}", prefix, self.text)
     }
 
-    /// Whether any segment is a WowLink (contains clickable item/spell links).
+    /// Creature entry of the NPC that sent a monster message.
+    pub fn npc_entry(&self) -> Option<u32> {
+        if !self.message_type.is_monster() {
+            return None;
+        }
+        match self.sender_guid.kind() {
+            GuidKind::Creature | GuidKind::Vehicle => self.sender_guid.entry(),
+            _ => None,
+        }
+    }
+
+    /// Whether any segment is a WowLink or Player link (clickable item/spell/player links).
     pub fn has_links(&self) -> bool {
-        self.segments.iter().any(|s| matches!(s, TextSegment::WowLink { .. }))
+        self.segments
+            .iter()
+            .any(|s| matches!(s, TextSegment::WowLink { .. } | TextSegment::Player { .. }))
     }
 }
 
@@ -429,44 +833,31 @@ fn extract_channel_name(formatted: &str, channel_number: u32) -> String {
     String::new()
 }
 
-/// Parse a WoW hyperlink type string like "item:49908:0:0:..." into a WowLinkType.
-fn parse_wow_link_type(data: &str) -> WowLinkType {
-    let (kind, rest) = data.split_once(':').unwrap_or((data, ""));
-    let id: u32 = rest
-        .split(':')
-        .next()
-        .unwrap_or("")
-        .parse()
-        .unwrap_or(0);
-    match kind {
-        "item" => WowLinkType::Item(id),
-        "spell" | "enchant" => WowLinkType::Spell(id),
-        "achievement" => WowLinkType::Achievement(id),
-        "quest" => WowLinkType::Quest(id),
-        "trade" => WowLinkType::Trade(id),
-        _ => WowLinkType::Other,
-    }
-}
-
 /// Parse WoW formatted text into rich TextSegments with colors and clickable links.
 ///
 /// Handles: |cffRRGGBB (color), |r (reset), |H...|h (link start), |h (link end), |T...|t (texture skip).
+/// A link's `raw` is the exact source text, from its `|c` (when the color code directly
+/// precedes `|H`) through the closing `|h` and a `|r` right after it.
 fn parse_text_segments(raw: &str) -> Vec<TextSegment> {
     let mut segments: Vec<TextSegment> = Vec::new();
     let mut current_text = String::new();
     let mut current_color: Option<[f32; 4]> = None;
-    let mut pending_link: Option<WowLinkType> = None;
+    let mut pending_link: Option<String> = None;
     let mut link_color: Option<[f32; 4]> = None;
+    // Byte offset of the last `|c`, while nothing but escapes followed it.
+    let mut color_start: Option<usize> = None;
+    // Byte offset where the pending link's source text starts.
+    let mut link_start = 0;
 
-    let mut chars = raw.chars().peekable();
+    let mut chars = raw.char_indices().peekable();
 
-    while let Some(ch) = chars.next() {
+    while let Some((pos, ch)) = chars.next() {
         if ch == '|' {
-            match chars.peek().copied() {
+            match chars.peek().map(|&(_, c)| c) {
                 Some('c') | Some('C') => {
                     // |cffRRGGBB — set color
                     chars.next(); // consume 'c'
-                    let hex: String = (&mut chars).take(8).collect();
+                    let hex: String = (&mut chars).take(8).map(|(_, c)| c).collect();
                     if hex.len() == 8 {
                         // First 2 chars are alpha (usually ff), next 6 are RRGGBB
                         let r = u8::from_str_radix(&hex[2..4], 16).unwrap_or(255);
@@ -474,12 +865,14 @@ fn parse_text_segments(raw: &str) -> Vec<TextSegment> {
                         let b = u8::from_str_radix(&hex[6..8], 16).unwrap_or(255);
                         current_color =
                             Some([r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, 1.0]);
+                        color_start = Some(pos);
                     }
                 }
                 Some('r') | Some('R') => {
                     // |r — reset color
                     chars.next();
                     current_color = None;
+                    color_start = None;
                 }
                 Some('H') => {
                     // |H...|h — hyperlink data, extract link type
@@ -489,25 +882,45 @@ fn parse_text_segments(raw: &str) -> Vec<TextSegment> {
                         segments.push(TextSegment::Plain(std::mem::take(&mut current_text)));
                     }
                     let mut link_data = String::new();
-                    while let Some(c) = chars.next() {
-                        if c == '|' && chars.peek() == Some(&'h') {
+                    while let Some((_, c)) = chars.next() {
+                        if c == '|' && chars.peek().map(|&(_, c)| c) == Some('h') {
                             chars.next(); // consume 'h'
                             break;
                         }
                         link_data.push(c);
                     }
-                    pending_link = Some(parse_wow_link_type(&link_data));
+                    pending_link = Some(link_data);
                     link_color = current_color;
+                    link_start = color_start.unwrap_or(pos);
                 }
                 Some('h') => {
                     // |h — end of link display name
                     chars.next();
-                    if let Some(lt) = pending_link.take() {
+                    if let Some(data) = pending_link.take() {
+                        let mut end = chars.peek().map_or(raw.len(), |&(i, _)| i);
+                        if raw[end..].starts_with("|r") || raw[end..].starts_with("|R") {
+                            chars.next();
+                            chars.next();
+                            end += 2;
+                            current_color = None;
+                            color_start = None;
+                        }
                         let display_name = std::mem::take(&mut current_text);
-                        segments.push(TextSegment::WowLink {
-                            link_type: lt,
-                            display_name,
-                            color: link_color.unwrap_or([1.0, 1.0, 1.0, 1.0]),
+                        let raw = raw[link_start..end].to_string();
+                        let color = link_color.unwrap_or([1.0, 1.0, 1.0, 1.0]);
+                        segments.push(match WowLinkType::parse(&data) {
+                            WowLinkType::Player(name) => TextSegment::Player {
+                                name,
+                                display_name,
+                                color,
+                                raw,
+                            },
+                            link_type => TextSegment::WowLink {
+                                link_type,
+                                display_name,
+                                color,
+                                raw,
+                            },
                         });
                         link_color = None;
                     }
@@ -515,8 +928,8 @@ fn parse_text_segments(raw: &str) -> Vec<TextSegment> {
                 Some('T') => {
                     // |T...|t — texture, skip entirely
                     chars.next();
-                    while let Some(c) = chars.next() {
-                        if c == '|' && chars.peek() == Some(&'t') {
+                    while let Some((_, c)) = chars.next() {
+                        if c == '|' && chars.peek().map(|&(_, c)| c) == Some('t') {
                             chars.next();
                             break;
                         }
@@ -525,10 +938,12 @@ fn parse_text_segments(raw: &str) -> Vec<TextSegment> {
                 _ => {
                     // Unknown escape, keep the pipe
                     current_text.push(ch);
+                    color_start = None;
                 }
             }
         } else {
             current_text.push(ch);
+            color_start = None;
         }
     }
 
@@ -590,117 +1005,323 @@ fn strip_wow_formatting(text: &str) -> String {
     result
 }
 
-// ─── Chat Reader (buffer-scanning approach) ─────────────────────────
+// ─── Chat Reader (sequence-number approach) ─────────────────────────
+//
+// Each poll first reads only the sequence word of every slot plus the
+// next-write index, in one batched `read_memory_ranges` call, and then fetches
+// just the slots that advanced instead of the whole ~360 KB buffer. Every
+// message carries a per-message incrementing counter at MSG_SEQUENCE, so new
+// messages are the slots whose sequence is above the highest one already seen,
+// and sorting by sequence gives the exact arrival order. A gap between the
+// last seen sequence and the oldest new one means the ring wrapped between
+// polls and those messages were overwritten before we could read them.
+//
+// The next-write index at `chat_buffer_count` is used to cross-check the
+// newest slot, and as the fallback when a build leaves sequences at zero.
 //
-// Instead of relying on a single "count" address (which may be wrong for
-// some WoW builds), we read the entire 60-slot buffer each poll and detect
-// which slots changed by comparing a fingerprint (first 16 bytes of each
-// entry).  New/changed slots are parsed and returned as new messages.
+// New slots are re-read before parsing so a message the game is still
+// writing is never emitted half-done.
 
-const FINGERPRINT_LEN: usize = 16;
-const TOTAL_BUFFER_SIZE: usize = offsets::CHAT_BUFFER_SIZE * offsets::CHAT_MESSAGE_STRIDE;
+/// Re-reads of a new slot before giving up on it until the next poll.
+const TORN_READ_ATTEMPTS: usize = 3;
+pub const TOTAL_BUFFER_SIZE: usize = offsets::CHAT_BUFFER_SIZE * offsets::CHAT_MESSAGE_STRIDE;
 
 pub struct ChatReader {
-    fingerprints: [[u8; FINGERPRINT_LEN]; offsets::CHAT_BUFFER_SIZE],
+    /// Highest MSG_SEQUENCE returned so far.
+    last_sequence: u32,
+    /// Next-write index at the previous poll (None if unreadable).
+    last_index: Option<usize>,
+    /// Newest slot at the previous poll, without sequences: a changed slot under an
+    /// unchanged index means a whole ring of 60 messages was written.
+    last_newest_slot: Option<Vec<u8>>,
     initialized: bool,
+    recorder: Option<PollRecorder>,
+    /// Sender names from the Name Store for messages that don't carry one.
+    names: NameCache,
 }
 
 impl ChatReader {
     pub fn new() -> Self {
         Self {
-            fingerprints: [[0u8; FINGERPRINT_LEN]; offsets::CHAT_BUFFER_SIZE],
+            last_sequence: 0,
+            last_index: None,
+            last_newest_slot: None,
             initialized: false,
+            recorder: None,
+            names: NameCache::new(),
         }
     }
 
+    /// Reset change detection and the name cache. Also stops any active recording.
     pub fn reset(&mut self) {
         info!("ChatReader reset");
-        self.fingerprints = [[0u8; FINGERPRINT_LEN]; offsets::CHAT_BUFFER_SIZE];
+        self.last_sequence = 0;
+        self.last_index = None;
+        self.last_newest_slot = None;
         self.initialized = false;
+        self.recorder = None;
+        self.names.clear();
     }
 
-    /// Poll the chat buffer for new messages by scanning all 60 slots.
-    pub fn poll(&mut self, reader: &dyn ProcessMemoryReader) -> io::Result<Vec<ChatMessage>> {
-        // Read entire buffer in one syscall (~360 KB).
-        let buffer = reader.read_memory(offsets::CHAT_BUFFER_START, TOTAL_BUFFER_SIZE)?;
-        if buffer.len() < TOTAL_BUFFER_SIZE {
-            warn!(
-                "poll: buffer read returned {} bytes, expected {}",
-                buffer.len(),
-                TOTAL_BUFFER_SIZE,
-            );
-            return Ok(Vec::new());
-        }
+    /// Record every buffer read by `poll` until `stop_recording` or `reset`.
+    pub fn start_recording(&mut self, recorder: PollRecorder) {
+        self.recorder = Some(recorder);
+    }
 
-        // First poll — parse all existing messages instead of skipping.
-        let is_first = !self.initialized;
+    pub fn stop_recording(&mut self) {
+        self.recorder = None;
+    }
 
-        let mut new_messages: Vec<(u32, ChatMessage)> = Vec::new();
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
 
-        for i in 0..offsets::CHAT_BUFFER_SIZE {
-            let offset = i * offsets::CHAT_MESSAGE_STRIDE;
-            let fp: [u8; FINGERPRINT_LEN] = buffer[offset..offset + FINGERPRINT_LEN]
-                .try_into()
-                .unwrap();
+    /// Poll the chat buffer for messages written since the last poll, oldest first.
+    ///
+    /// If more than 60 messages arrived in between, a "messages lost" marker is
+    /// returned ahead of them.
+    pub fn poll(
+        &mut self,
+        reader: &dyn ProcessMemoryReader,
+        offsets: &Offsets,
+    ) -> io::Result<Vec<ChatMessage>> {
+        if self.recorder.as_ref().is_some_and(|r| r.needs_keyframe()) {
+            self.record_keyframe(reader, offsets);
+        }
 
-            if !is_first && fp == self.fingerprints[i] {
-                continue; // Slot unchanged.
-            }
+        // One batched read: the sequence word of every slot, then the next-write index.
+        let mut ranges: Vec<(usize, usize)> = (0..offsets::CHAT_BUFFER_SIZE)
+            .map(|i| (slot_address(offsets, i) + offsets::MSG_SEQUENCE, 4))
+            .collect();
+        ranges.push((offsets.chat_buffer_count, 4));
+        let mut words = reader.read_memory_ranges(&ranges)?;
+        let next_index = words.pop().and_then(|data| parse_next_index(&data));
+        if words.iter().any(|w| w.len() < 4) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "sequence words unreadable — buffer start 0x{:X} may be wrong",
+                    offsets.chat_buffer_start,
+                ),
+            ));
+        }
+        let sequences: Vec<u32> = words.iter().map(|w| read_u32(w, 0)).collect();
+        let max_sequence = sequences.iter().copied().max().unwrap_or(0);
 
-            // Skip all-zero fingerprints (slot was cleared, not a real message).
-            if fp == [0u8; FINGERPRINT_LEN] {
-                self.fingerprints[i] = fp;
-                continue;
+        let mut is_first = !self.initialized;
+        if !is_first && max_sequence < self.last_sequence {
+            info!(
+                "poll: sequence went back ({} -> {}), client reloaded — resyncing",
+                self.last_sequence, max_sequence,
+            );
+            is_first = true;
+        }
+
+        let mut lost = None;
+        let slots: Vec<usize> = if max_sequence > 0 {
+            if let Some(index) = next_index {
+                let newest = (index + offsets::CHAT_BUFFER_SIZE - 1) % offsets::CHAT_BUFFER_SIZE;
+                if sequences[newest] != max_sequence {
+                    debug!(
+                        "poll: next-write index {} disagrees with newest sequence (slot {} seq={}, max={})",
+                        index, newest, sequences[newest], max_sequence,
+                    );
+                }
             }
 
-            debug!("  slot {} changed, fp={:02X?}", i, &fp);
+            let floor = if is_first { 0 } else { self.last_sequence };
+            let mut fresh: Vec<usize> = (0..offsets::CHAT_BUFFER_SIZE)
+                .filter(|&i| sequences[i] > floor)
+                .collect();
+            fresh.sort_by_key(|&i| sequences[i]);
 
-            let slot_data = &buffer[offset..offset + offsets::CHAT_MESSAGE_STRIDE];
-            match ChatMessage::from_raw_bytes(slot_data) {
-                Some(msg) => {
-                    let order_key = if msg.timestamp > 0 {
-                        msg.timestamp
+            if let Some(&oldest) = fresh.first() {
+                if !is_first && sequences[oldest] > self.last_sequence + 1 {
+                    lost = Some(Some(sequences[oldest] - self.last_sequence - 1));
+                }
+            }
+            fresh
+        } else {
+            // No sequence numbers — walk the ring from the last next-write index. An
+            // unchanged index is either no new messages or exactly 60 (or a multiple);
+            // the newest slot's contents tell them apart.
+            let newest_slot = next_index.and_then(|index| {
+                let newest = (index + offsets::CHAT_BUFFER_SIZE - 1) % offsets::CHAT_BUFFER_SIZE;
+                reader
+                    .read_memory(slot_address(offsets, newest), offsets::CHAT_MESSAGE_STRIDE)
+                    .ok()
+            });
+            let slots = match (is_first, self.last_index, next_index) {
+                (true, _, Some(index)) => ring_range(index, index, true),
+                (true, _, None) => (0..offsets::CHAT_BUFFER_SIZE).collect(),
+                (false, Some(last), Some(index)) if last == index => {
+                    let changed = matches!(
+                        (&self.last_newest_slot, &newest_slot),
+                        (Some(before), Some(now)) if before != now
+                    );
+                    if changed {
+                        lost = Some(None);
+                        ring_range(index, index, true)
                     } else {
-                        i as u32
-                    };
-                    new_messages.push((order_key, msg));
+                        Vec::new()
+                    }
+                }
+                (false, Some(last), Some(index)) => ring_range(last, index, false),
+                _ => Vec::new(),
+            };
+            self.last_newest_slot = newest_slot;
+            slots
+        };
+
+        let mut new_messages = Vec::new();
+        match lost {
+            Some(Some(count)) => {
+                warn!("poll: {} messages lost (buffer wrapped between polls)", count);
+                new_messages.push(ChatMessage::lost_marker(Some(count)));
+            }
+            Some(None) => {
+                warn!("poll: next-write index unchanged but newest slot rewritten — full wrap");
+                new_messages.push(ChatMessage::lost_marker(None));
+            }
+            None => {}
+        }
+
+        // Fetch only the new slots, twice, so torn reads can be spotted by comparison.
+        let slot_ranges: Vec<(usize, usize)> = slots
+            .iter()
+            .map(|&i| (slot_address(offsets, i), offsets::CHAT_MESSAGE_STRIDE))
+            .collect();
+        let first = reader.read_memory_ranges(&slot_ranges).unwrap_or_default();
+        let second = reader.read_memory_ranges(&slot_ranges).unwrap_or_default();
+
+        let mut last_sequence = max_sequence;
+        let mut last_index = next_index;
+        let mut read_slots: Vec<(usize, Vec<u8>)> = Vec::new();
+        for (k, &i) in slots.iter().enumerate() {
+            debug!("  slot {} new, seq={}", i, sequences[i]);
+
+            // The game may be writing this slot while we read it. Only parse it once two
+            // consecutive reads agree; otherwise leave it (and everything after it) for
+            // the next poll.
+            let stable = match (first.get(k), second.get(k)) {
+                (Some(a), Some(b)) if a.len() == offsets::CHAT_MESSAGE_STRIDE && a == b => {
+                    Some(b.clone())
+                }
+                (_, Some(b)) if b.len() == offsets::CHAT_MESSAGE_STRIDE => {
+                    read_stable_slot(reader, offsets, i, b)
+                }
+                _ => None,
+            }
+            .filter(|data| read_u32(data, offsets::MSG_SEQUENCE) == sequences[i]);
+            let Some(slot_data) = stable else {
+                debug!("  slot {}: torn read, deferring to next poll", i);
+                last_sequence = sequences[i].saturating_sub(1);
+                last_index = Some(i);
+                break;
+            };
+
+            match ChatMessage::from_raw_bytes(&slot_data) {
+                Some(mut msg) => {
+                    if msg.sender_name.is_empty() {
+                        if let Some(name) = self.names.resolve(reader, offsets, msg.sender_guid) {
+                            debug!("  slot {}: sender \"{}\" from Name Store", i, name);
+                            msg.sender_name = name;
+                        }
+                    }
+                    new_messages.push(msg);
                 }
                 None => debug!("  slot {}: parsed as None (empty/invalid)", i),
             }
+            read_slots.push((i, slot_data));
+        }
 
-            self.fingerprints[i] = fp;
+        if let Some(recorder) = self.recorder.as_mut() {
+            let slots: Vec<(usize, &[u8])> =
+                read_slots.iter().map(|(i, data)| (*i, data.as_slice())).collect();
+            if let Err(e) = recorder.record_slots(&slots) {
+                warn!("poll: recording failed, stopping: {}", e);
+                self.recorder = None;
+            }
         }
 
         if is_first {
-            self.initialized = true;
             info!(
                 "poll: first sync — loaded {} existing messages from buffer",
                 new_messages.len(),
             );
         }
+        self.initialized = true;
+        self.last_sequence = last_sequence;
+        self.last_index = last_index;
 
-        // Sort by timestamp so messages appear in chronological order.
-        new_messages.sort_by_key(|(key, _)| *key);
+        Ok(new_messages)
+    }
 
-        if !new_messages.is_empty() && is_first {
-            info!("poll: first sync — loaded {} existing messages from buffer", new_messages.len());
+    /// Record the whole buffer once so a recording starts with the messages already in it.
+    fn record_keyframe(&mut self, reader: &dyn ProcessMemoryReader, offsets: &Offsets) {
+        let Some(recorder) = self.recorder.as_mut() else {
+            return;
+        };
+        let result = reader
+            .read_memory(offsets.chat_buffer_start, TOTAL_BUFFER_SIZE)
+            .and_then(|buffer| recorder.record(&buffer));
+        if let Err(e) = result {
+            warn!("poll: recording failed, stopping: {}", e);
+            self.recorder = None;
         }
+    }
+}
 
-        Ok(new_messages.into_iter().map(|(_, msg)| msg).collect())
+/// Re-read `slot` until two consecutive reads agree, starting from `first`.
+/// `None` if it is still changing after `TORN_READ_ATTEMPTS` re-reads.
+fn read_stable_slot(
+    reader: &dyn ProcessMemoryReader,
+    offsets: &Offsets,
+    slot: usize,
+    first: &[u8],
+) -> Option<Vec<u8>> {
+    let address = slot_address(offsets, slot);
+    let mut previous = first.to_vec();
+    for attempt in 1..=TORN_READ_ATTEMPTS {
+        let current = reader.read_memory(address, offsets::CHAT_MESSAGE_STRIDE).ok()?;
+        if current == previous {
+            return Some(current);
+        }
+        debug!("  slot {}: changed between reads (attempt {})", slot, attempt);
+        previous = current;
     }
+    None
+}
+
+/// Decode the next-write index; `None` if the read was short or the value out of range.
+fn parse_next_index(data: &[u8]) -> Option<usize> {
+    let index = read_u32(data, 0) as usize;
+    (data.len() >= 4 && index < offsets::CHAT_BUFFER_SIZE).then_some(index)
+}
+
+/// Slots from `from` up to (not including) `to`, wrapping at 60.
+/// With `full` and `from == to`, the whole ring starting at `from` (oldest first).
+fn ring_range(from: usize, to: usize, full: bool) -> Vec<usize> {
+    let len = match (to + offsets::CHAT_BUFFER_SIZE - from) % offsets::CHAT_BUFFER_SIZE {
+        0 if full => offsets::CHAT_BUFFER_SIZE,
+        n => n,
+    };
+    (0..len)
+        .map(|k| (from + k) % offsets::CHAT_BUFFER_SIZE)
+        .collect()
 }
 
 // ─── Debug Scan ─────────────────────────────────────────────────────
 
 /// Read diagnostic info from the chat buffer and log it.
 /// Call this manually (via UI button) to diagnose offset issues.
-pub fn debug_scan(reader: &dyn ProcessMemoryReader) {
+pub fn debug_scan(reader: &dyn ProcessMemoryReader, offsets: &Offsets) {
     info!("=== DEBUG SCAN START ===");
 
     // 1. Try count addresses.
     for (label, addr) in [
-        ("CHAT_BUFFER_COUNT", offsets::CHAT_BUFFER_COUNT),
-        ("CHAT_BUFFER_COUNT_ALT", offsets::CHAT_BUFFER_COUNT_ALT),
+        ("CHAT_BUFFER_COUNT", offsets.chat_buffer_count),
+        ("CHAT_BUFFER_COUNT_ALT", offsets.chat_buffer_count_alt),
     ] {
         match reader.read_memory(addr, 4) {
             Ok(data) if data.len() >= 4 => {
@@ -714,14 +1335,14 @@ pub fn debug_scan(reader: &dyn ProcessMemoryReader) {
 
     // 2. Read entire buffer and summarize each slot.
     info!("  Reading entire buffer ({} bytes)...", TOTAL_BUFFER_SIZE);
-    let buffer = match reader.read_memory(offsets::CHAT_BUFFER_START, TOTAL_BUFFER_SIZE) {
+    let buffer = match reader.read_memory(offsets.chat_buffer_start, TOTAL_BUFFER_SIZE) {
         Ok(b) if b.len() >= TOTAL_BUFFER_SIZE => b,
         Ok(b) => {
             warn!(
                 "  Buffer read: only {} / {} bytes — buffer start 0x{:X} may be WRONG",
                 b.len(),
                 TOTAL_BUFFER_SIZE,
-                offsets::CHAT_BUFFER_START,
+                offsets.chat_buffer_start,
             );
             info!("=== DEBUG SCAN END ===");
             return;
@@ -729,7 +1350,7 @@ pub fn debug_scan(reader: &dyn ProcessMemoryReader) {
         Err(e) => {
             warn!(
                 "  Buffer read FAILED at 0x{:X}: {} — buffer start is WRONG",
-                offsets::CHAT_BUFFER_START,
+                offsets.chat_buffer_start,
                 e,
             );
             info!("=== DEBUG SCAN END ===");
@@ -776,6 +1397,97 @@ pub fn debug_scan(reader: &dyn ProcessMemoryReader) {
     info!("=== DEBUG SCAN END ===");
 }
 
+// ─── Slot Inspector ──────────────────────────────────────────────────
+
+/// Raw bytes shown next to a string field; the rest is in the hex viewer.
+const INSPECT_RAW_PREVIEW: usize = 32;
+/// Length of the sender name field (`MSG_SENDER_NAME`, char[49]).
+const SENDER_NAME_LEN: usize = 49;
+
+/// One decoded field of a chat slot, with the bytes it was decoded from.
+pub struct SlotField {
+    pub name: &'static str,
+    /// Offset within the slot.
+    pub offset: usize,
+    pub value: String,
+    pub raw: Vec<u8>,
+}
+
+/// Address of `slot` in the chat ring.
+pub fn slot_address(offsets: &Offsets, slot: usize) -> usize {
+    offsets.chat_buffer_start + slot * offsets::CHAT_MESSAGE_STRIDE
+}
+
+/// Decode every field of a slot read from the chat ring (`data` starts at the slot).
+pub fn decode_slot(data: &[u8]) -> Vec<SlotField> {
+    let raw = |offset: usize, len: usize| -> Vec<u8> {
+        let end = (offset + len).min(data.len());
+        data.get(offset..end).unwrap_or_default().to_vec()
+    };
+    let string = |name, offset, max_len: usize| {
+        let value = read_cstring(data, offset, max_len);
+        SlotField {
+            name,
+            offset,
+            raw: raw(offset, INSPECT_RAW_PREVIEW),
+            value: format!("\"{}\" ({} bytes)", value, value.len()),
+        }
+    };
+    let number = |name, offset, value: String| SlotField {
+        name,
+        offset,
+        raw: raw(offset, 4),
+        value,
+    };
+
+    let msg_type = read_u32(data, offsets::MSG_TYPE);
+    let timestamp = read_u32(data, offsets::MSG_TIMESTAMP);
+    vec![
+        SlotField {
+            name: "Sender GUID",
+            offset: offsets::MSG_SENDER_GUID,
+            raw: raw(offsets::MSG_SENDER_GUID, 8),
+            value: {
+                let guid = Guid(read_u64(data, offsets::MSG_SENDER_GUID));
+                match guid.entry() {
+                    Some(entry) => format!("{} ({} entry {})", guid, guid.kind().label(), entry),
+                    None => format!("{} ({})", guid, guid.kind().label()),
+                }
+            },
+        },
+        string("Sender name", offsets::MSG_SENDER_NAME, SENDER_NAME_LEN),
+        string("Formatted", offsets::MSG_FORMATTED, offsets::MSG_STRING_MAX_LEN),
+        string("Plain text", offsets::MSG_PLAIN_TEXT, offsets::MSG_STRING_MAX_LEN),
+        number(
+            "Type",
+            offsets::MSG_TYPE,
+            format!("{} ({})", msg_type, ChatMessageType::from_u32(msg_type).label()),
+        ),
+        number(
+            "Channel",
+            offsets::MSG_CHANNEL_NUM,
+            read_u32(data, offsets::MSG_CHANNEL_NUM).to_string(),
+        ),
+        number(
+            "Sequence",
+            offsets::MSG_SEQUENCE,
+            read_u32(data, offsets::MSG_SEQUENCE).to_string(),
+        ),
+        number(
+            "Timestamp",
+            offsets::MSG_TIMESTAMP,
+            match chrono::DateTime::from_timestamp(timestamp as i64, 0) {
+                Some(time) if timestamp != 0 => format!(
+                    "{} ({})",
+                    timestamp,
+                    time.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S")
+                ),
+                _ => timestamp.to_string(),
+            },
+        ),
+    ]
+}
+
 // ─── Scan Analysis ───────────────────────────────────────────────────
 
 /// Analyze addresses where a search string was found, looking for chat buffer patterns.
@@ -802,7 +1514,7 @@ pub fn analyze_found_addresses(addresses: &[usize]) {
     for i in 0..check_limit {
         for j in (i + 1)..check_limit {
             let diff = addresses[j].saturating_sub(addresses[i]);
-            if diff > 0 && diff % offsets::CHAT_MESSAGE_STRIDE == 0 {
+            if diff > 0 && diff.is_multiple_of(offsets::CHAT_MESSAGE_STRIDE) {
                 let slots = diff / offsets::CHAT_MESSAGE_STRIDE;
                 if slots <= offsets::CHAT_BUFFER_SIZE {
                     info!(
@@ -841,6 +1553,137 @@ pub fn analyze_found_addresses(addresses: &[usize]) {
     info!("=== SCAN ANALYSIS END ===");
 }
 
+// ─── Buffer Discovery ────────────────────────────────────────────────
+
+/// A possible chat buffer start derived from a phrase match.
+#[derive(Debug, Clone)]
+pub struct BufferCandidate {
+    pub buffer_start: usize,
+    /// Slot the phrase was found in.
+    pub slot: usize,
+    /// `"FormattedMessage"` or `"PlainText"` — which field the match fell in.
+    pub field: &'static str,
+    /// Slots in `[buffer_start, +60 × stride)` that look like chat messages.
+    pub valid_slots: usize,
+}
+
+/// Turn phrase matches into ranked chat buffer candidates.
+///
+/// Every match is walked back to the start of its string, which is treated as the
+/// `MSG_FORMATTED` or `MSG_PLAIN_TEXT` field, giving a message base. The memory around
+/// that base is read as slots; the buffer start is the 60-slot window with the most
+/// slots that parse as `ChatMessage` (ties go to a window whose first slot is valid,
+/// then the lowest address). Only bases where the phrase appears in both text fields
+/// are kept.
+pub fn find_buffer_candidates(
+    reader: &dyn ProcessMemoryReader,
+    addresses: &[usize],
+    phrase: &str,
+) -> Vec<BufferCandidate> {
+    const STRIDE: usize = offsets::CHAT_MESSAGE_STRIDE;
+    const SLOTS: usize = offsets::CHAT_BUFFER_SIZE;
+
+    let mut candidates: Vec<BufferCandidate> = Vec::new();
+    for &addr in addresses.iter().take(200) {
+        for (field, field_offset) in [
+            ("FormattedMessage", offsets::MSG_FORMATTED),
+            ("PlainText", offsets::MSG_PLAIN_TEXT),
+        ] {
+            let Some(string_start) = string_start(reader, addr) else {
+                continue;
+            };
+            let Some(msg_base) = string_start.checked_sub(field_offset) else {
+                continue;
+            };
+
+            // Slots [msg_base - 59 strides, msg_base + 59 strides] that parse as a
+            // `ChatMessage`; `plausible[SLOTS - 1]` is msg_base.
+            let plausible: Vec<bool> = (0..2 * SLOTS - 1)
+                .map(|k| {
+                    (msg_base + k * STRIDE)
+                        .checked_sub((SLOTS - 1) * STRIDE)
+                        .and_then(|slot_addr| reader.read_memory(slot_addr, STRIDE).ok())
+                        .filter(|data| data.len() == STRIDE)
+                        .is_some_and(|data| ChatMessage::from_raw_bytes(&data).is_some())
+                })
+                .collect();
+            if !plausible[SLOTS - 1] {
+                continue;
+            }
+            let slot_data = match reader.read_memory(msg_base, STRIDE) {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+            // The game writes the text into both fields; a base that only has it in one
+            // is the other field misread at the wrong offset.
+            let in_both = [offsets::MSG_FORMATTED, offsets::MSG_PLAIN_TEXT]
+                .into_iter()
+                .all(|off| {
+                    read_cstring(&slot_data, off, offsets::MSG_STRING_MAX_LEN).contains(phrase)
+                });
+            if !in_both {
+                continue;
+            }
+
+            // Window starting `SLOTS - 1 - slot` entries before msg_base puts it at `slot`.
+            let best = (0..SLOTS)
+                .map(|slot| {
+                    let first = SLOTS - 1 - slot;
+                    let score = plausible[first..first + SLOTS].iter().filter(|&&v| v).count();
+                    (slot, score, plausible[first])
+                })
+                .max_by_key(|&(slot, score, first_valid)| (score, first_valid, slot));
+            let Some((slot, valid_slots, _)) = best else {
+                continue;
+            };
+
+            let buffer_start = msg_base - slot * STRIDE;
+            if candidates.iter().any(|c| c.buffer_start == buffer_start) {
+                continue;
+            }
+            info!(
+                "  candidate: start=0x{:08X} (match 0x{:08X} in {} of slot {}), {}/{} valid slots",
+                buffer_start, addr, field, slot, valid_slots, SLOTS,
+            );
+            candidates.push(BufferCandidate {
+                buffer_start,
+                slot,
+                field,
+                valid_slots,
+            });
+        }
+    }
+
+    candidates.sort_by_key(|c| std::cmp::Reverse(c.valid_slots));
+    candidates
+}
+
+/// Start of the null-terminated string containing `addr`: one past the nearest
+/// preceding zero byte, at most `MSG_STRING_MAX_LEN` back.
+fn string_start(reader: &dyn ProcessMemoryReader, addr: usize) -> Option<usize> {
+    let from = addr.checked_sub(offsets::MSG_STRING_MAX_LEN)?;
+    let data = reader.read_memory(from, addr - from).ok()?;
+    let zero = data.iter().rposition(|&b| b == 0)?;
+    Some(from + zero + 1)
+}
+
+/// Whether a slot-sized block of memory looks like a populated chat message: a small
+/// message type and null-terminated UTF-8 text fields, at least one of them non-empty.
+pub fn is_plausible_slot(data: &[u8]) -> bool {
+    if data.len() < offsets::CHAT_MESSAGE_STRIDE || read_u32(data, offsets::MSG_TYPE) > 0xFF {
+        return false;
+    }
+    let field = |offset: usize| {
+        let slice = &data[offset..offset + offsets::MSG_STRING_MAX_LEN];
+        let end = slice.iter().position(|&b| b == 0)?;
+        std::str::from_utf8(&slice[..end]).ok().map(str::len)
+    };
+    match (field(offsets::MSG_FORMATTED), field(offsets::MSG_PLAIN_TEXT)) {
+        (Some(formatted), Some(text)) => formatted > 0 || text > 0,
+        _ => false,
+    }
+}
+
 // ─── Chat Tabs (message-type filter groups) ─────────────────────────
 
 pub struct ChatTab {
@@ -887,9 +1730,13 @@ pub fn default_tabs() -> Vec<ChatTab> {
                 ChatMessageType::MonsterYell,
                 ChatMessageType::MonsterWhisper,
                 ChatMessageType::MonsterEmote,
+                ChatMessageType::RaidBossEmote,
+                ChatMessageType::RaidBossWhisper,
                 ChatMessageType::System,
                 ChatMessageType::Afk,
                 ChatMessageType::Dnd,
+                ChatMessageType::Achievement,
+                ChatMessageType::GuildAchievement,
             ]),
         },
         ChatTab {
@@ -898,15 +1745,70 @@ pub fn default_tabs() -> Vec<ChatTab> {
                 ChatMessageType::Skill,
                 ChatMessageType::Loot,
                 ChatMessageType::System,
+                ChatMessageType::Money,
+                ChatMessageType::Opening,
+                ChatMessageType::Tradeskills,
+                ChatMessageType::PetInfo,
+                ChatMessageType::CombatMiscInfo,
+                ChatMessageType::CombatXpGain,
+                ChatMessageType::CombatHonorGain,
+                ChatMessageType::CombatFactionChange,
+                ChatMessageType::BgSystemNeutral,
+                ChatMessageType::BgSystemAlliance,
+                ChatMessageType::BgSystemHorde,
             ]),
         },
         ChatTab {
             name: "Group".into(),
             filter: Some(vec![
                 ChatMessageType::Party,
+                ChatMessageType::PartyLeader,
                 ChatMessageType::Raid,
+                ChatMessageType::RaidLeader,
+                ChatMessageType::RaidWarning,
+                ChatMessageType::Battleground,
+                ChatMessageType::BattlegroundLeader,
                 ChatMessageType::MonsterParty,
             ]),
         },
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wow_strings(text: &str) -> Vec<String> {
+        parse_text_segments(text)
+            .iter()
+            .map(|s| s.to_wow_string().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn link_raw_is_the_source_text() {
+        let item = "|cffa335ee|Hitem:49908:0:0:0:0:0:0:0:80|h[Primordial Saronite]|h|r";
+        assert_eq!(wow_strings(&format!("WTS {} now", item)), ["WTS ", item, " now"]);
+
+        // No `|r` after the link: none is added.
+        let player = "|Hplayer:Alice|h[Alice]|h";
+        assert_eq!(wow_strings(&format!("{}: hi", player)), [player, ": hi"]);
+
+        // Textures and escapes inside the link are kept.
+        let spell = "|cff71d5ff|Hspell:48782|h[|TInterface\\Icons\\Spell:0|t Holy Light]|h|r";
+        assert_eq!(wow_strings(spell), [spell]);
+    }
+
+    #[test]
+    fn link_raw_starts_at_h_after_colored_text() {
+        // The color code belongs to the text before the link, not to the link.
+        let text = "|cffff0000red |Hplayer:Bob|h[Bob]|h|r";
+        let segments = parse_text_segments(text);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[1].to_wow_string(), "|Hplayer:Bob|h[Bob]|h|r");
+        assert!(matches!(
+            &segments[1],
+            TextSegment::Player { name, display_name, .. } if name == "Bob" && display_name == "[Bob]"
+        ));
+    }
+}
@@ -0,0 +1,273 @@
+use log::debug;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use sysinfo::{Pid, Process, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+use crate::memory;
+use crate::offsets::Offsets;
+use crate::player;
+
+// ─── Game client discovery ───────────────────────────────────────────
+
+/// A running process whose name matches the configured game executable.
+pub struct GameClient {
+    pub pid: u32,
+    /// Game executable; under Wine the Windows path mapped into the prefix rather
+    /// than `wine-preloader`.
+    pub exe: Option<PathBuf>,
+    /// Wine/Proton prefix the client runs in (Linux only).
+    pub wine_prefix: Option<PathBuf>,
+    /// Folder containing the game executable and `WTF`.
+    pub game_dir: Option<PathBuf>,
+    pub command_line: String,
+    /// Seconds since the Unix epoch.
+    pub start_time: u64,
+    /// Logged-in character, if the process could be read and is past the login screen.
+    pub character: Option<String>,
+}
+
+impl GameClient {
+    /// One-line description for the process picker.
+    pub fn label(&self) -> String {
+        let started = chrono::DateTime::from_timestamp(self.start_time as i64, 0)
+            .map(|t| t.with_timezone(&chrono::Local).format("%H:%M:%S").to_string())
+            .unwrap_or_else(|| "?".into());
+        format!(
+            "PID {}  {}  started {}",
+            self.pid,
+            self.character.as_deref().unwrap_or("(not logged in)"),
+            started,
+        )
+    }
+}
+
+/// List all processes running `process_name`, oldest first, reading each one's
+/// character name at `offsets.player_name` through a short-lived reader.
+pub fn list_game_clients(process_name: &str, offsets: &Offsets) -> Vec<GameClient> {
+    let mut sys = System::new();
+    sys.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing()
+            .with_exe(UpdateKind::OnlyIfNotSet)
+            .with_cmd(UpdateKind::OnlyIfNotSet),
+    );
+    let pids: Vec<Pid> = sys
+        .processes()
+        .values()
+        .filter(|p| is_game_process(p, process_name))
+        .map(|p| p.pid())
+        .collect();
+    // Environment and cwd are only needed to locate the Wine prefix and game folder.
+    sys.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&pids),
+        false,
+        ProcessRefreshKind::nothing()
+            .with_environ(UpdateKind::OnlyIfNotSet)
+            .with_cwd(UpdateKind::OnlyIfNotSet),
+    );
+
+    let mut clients: Vec<GameClient> = pids
+        .iter()
+        .filter_map(|pid| sys.process(*pid))
+        .map(|p| {
+            let wine_prefix = wine_prefix(p);
+            let exe = game_exe(p, process_name, wine_prefix.as_deref());
+            let game_dir = exe
+                .as_deref()
+                .and_then(Path::parent)
+                .map(Path::to_path_buf)
+                .or_else(|| p.cwd().filter(|d| d.join("WTF").is_dir()).map(Path::to_path_buf));
+            GameClient {
+                pid: p.pid().as_u32(),
+                exe: exe.or_else(|| p.exe().map(Path::to_path_buf)),
+                wine_prefix,
+                game_dir,
+                command_line: p
+                    .cmd()
+                    .iter()
+                    .map(|arg| arg.to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                start_time: p.start_time(),
+                character: None,
+            }
+        })
+        .collect();
+    clients.sort_by_key(|c| (c.start_time, c.pid));
+
+    for client in &mut clients {
+        client.character = read_character(client.pid, offsets);
+    }
+    clients
+}
+
+// ─── Background discovery ────────────────────────────────────────────
+
+/// Runs `list_game_clients` on a background thread for the auto-reattach watchdog, so
+/// the process scan and the per-client name reads never stall the UI.
+pub struct ClientWatcher {
+    request_tx: mpsc::Sender<(String, Offsets)>,
+    result_rx: mpsc::Receiver<Vec<GameClient>>,
+    /// A request was sent and its list has not been collected yet.
+    pending: bool,
+}
+
+impl ClientWatcher {
+    pub fn start() -> Self {
+        let (request_tx, request_rx) = mpsc::channel::<(String, Offsets)>();
+        let (result_tx, result_rx) = mpsc::channel();
+        thread::spawn(move || {
+            while let Ok((process_name, offsets)) = request_rx.recv() {
+                if result_tx.send(list_game_clients(&process_name, &offsets)).is_err() {
+                    break;
+                }
+            }
+        });
+        Self {
+            request_tx,
+            result_rx,
+            pending: false,
+        }
+    }
+
+    /// Ask for a fresh list, unless the previous one is still being built.
+    pub fn request(&mut self, process_name: &str, offsets: Offsets) {
+        if !self.pending {
+            self.pending = self.request_tx.send((process_name.to_string(), offsets)).is_ok();
+        }
+    }
+
+    /// The requested list, once it is ready.
+    pub fn try_result(&mut self) -> Option<Vec<GameClient>> {
+        let clients = self.result_rx.try_recv().ok()?;
+        self.pending = false;
+        Some(clients)
+    }
+}
+
+// ─── Process matching ────────────────────────────────────────────────
+
+/// Linux truncates the process name (`comm`) to 15 bytes.
+const COMM_MAX_LEN: usize = 15;
+
+/// Whether `p` runs the game executable `name`. Under Wine the process name can be
+/// `wine-preloader` or a truncated `Wow.exe`, so the exe path and the program in the
+/// command line (a Windows or Unix path) are checked too. Comparison ignores case.
+fn is_game_process(p: &Process, name: &str) -> bool {
+    let name = name.to_lowercase();
+    let proc_name = p.name().to_string_lossy().to_lowercase();
+    if proc_name == name
+        || (proc_name.len() == COMM_MAX_LEN && name.starts_with(&proc_name))
+    {
+        return true;
+    }
+    if p.exe().is_some_and(|exe| file_name(&exe.to_string_lossy()) == name) {
+        return true;
+    }
+    program_arg(p).is_some_and(|arg| file_name(&arg.to_string_lossy()) == name)
+}
+
+/// The program the process runs: argv[0], or argv[1] when argv[0] is a Wine loader
+/// (`wine Wow.exe`).
+fn program_arg(p: &Process) -> Option<&OsString> {
+    let cmd = p.cmd();
+    let first = cmd.first()?;
+    if file_name(&first.to_string_lossy()).starts_with("wine") {
+        cmd.get(1)
+    } else {
+        Some(first)
+    }
+}
+
+/// Lowercase last component of a Windows or Unix path.
+fn file_name(path: &str) -> String {
+    path.rsplit(['/', '\\']).next().unwrap_or(path).to_lowercase()
+}
+
+fn is_wine_process(p: &Process) -> bool {
+    let wine_exe = p
+        .exe()
+        .is_some_and(|exe| file_name(&exe.to_string_lossy()).starts_with("wine"));
+    wine_exe || program_arg(p).is_some_and(|arg| windows_drive(&arg.to_string_lossy()).is_some())
+}
+
+/// Wine prefix from `WINEPREFIX` (also set by Proton), else the default `~/.wine`.
+fn wine_prefix(p: &Process) -> Option<PathBuf> {
+    if !is_wine_process(p) {
+        return None;
+    }
+    let env = |key: &str| {
+        p.environ().iter().find_map(|var| {
+            let var = var.to_string_lossy();
+            var.strip_prefix(key)?.strip_prefix('=').map(str::to_string)
+        })
+    };
+    let prefix = env("WINEPREFIX")
+        .map(PathBuf::from)
+        .or_else(|| env("HOME").map(|home| Path::new(&home).join(".wine")))?;
+    debug!("PID={} runs in Wine prefix {}", p.pid(), prefix.display());
+    Some(prefix)
+}
+
+/// Drive letter of an absolute Windows path like `C:\Games\Wow.exe`.
+fn windows_drive(path: &str) -> Option<char> {
+    let mut chars = path.chars();
+    let drive = chars.next().filter(char::is_ascii_alphabetic)?;
+    (chars.next() == Some(':') && matches!(chars.next(), Some('\\') | Some('/')))
+        .then(|| drive.to_ascii_lowercase())
+}
+
+/// Path of the game executable on this machine. A Windows path in the command line is
+/// mapped through the prefix's `dosdevices` links (`c:` -> `drive_c`, `z:` -> `/`).
+fn game_exe(p: &Process, name: &str, prefix: Option<&Path>) -> Option<PathBuf> {
+    let arg = program_arg(p)?.to_string_lossy().into_owned();
+    if file_name(&arg) != name.to_lowercase() {
+        return None;
+    }
+    let path = match (windows_drive(&arg), prefix) {
+        (Some(drive), Some(prefix)) => {
+            let rest = arg[3..].replace('\\', "/");
+            prefix.join("dosdevices").join(format!("{}:", drive)).join(rest)
+        }
+        (Some(_), None) => return None,
+        (None, _) => {
+            let path = PathBuf::from(&arg);
+            if path.is_absolute() {
+                path
+            } else {
+                p.cwd()?.join(path)
+            }
+        }
+    };
+    path.is_file().then_some(path)
+}
+
+// ─── Character lookup ────────────────────────────────────────────────
+
+fn read_character(pid: u32, offsets: &Offsets) -> Option<String> {
+    let mut reader = memory::create_reader();
+    if let Err(e) = reader.attach(pid) {
+        debug!("Cannot read character name of PID={}: {}", pid, e);
+        return None;
+    }
+    let name = player::read_player_name(&*reader, offsets);
+    let _ = reader.detach();
+    name
+}
+
+/// Pick the client to attach to without asking: the only one, or the one logged in as
+/// `preferred_character`. Returns None when the choice is ambiguous.
+pub fn auto_select(clients: &[GameClient], preferred_character: &str) -> Option<usize> {
+    if clients.len() == 1 {
+        return Some(0);
+    }
+    if preferred_character.is_empty() {
+        return None;
+    }
+    clients
+        .iter()
+        .position(|c| c.character.as_deref() == Some(preferred_character))
+}
@@ -8,6 +8,14 @@ use std::path::PathBuf;
 #[serde(default)]
 pub struct AppConfig {
     pub process_name: String,
+    /// Offset profile name from `offsets.toml`; empty = pick by detected client build.
+    pub offset_profile: String,
+    /// How often the background thread reads the chat buffer and player info.
+    pub poll_interval_ms: u64,
+    /// Reattach automatically when the game process reappears after a crash or restart.
+    pub auto_reattach: bool,
+    /// Character of the last attached client; preferred when several clients are running.
+    pub last_character: String,
     pub wow_folder_path: String,
     pub selected_character: String,
     pub font_name: String,
@@ -19,12 +27,18 @@ pub struct AppConfig {
     pub auto_translate: bool,
     pub translator_source_lang: String,
     pub translator_target_lang: String,
+    /// Players whose messages are hidden in the chat tabs.
+    pub muted_senders: Vec<String>,
 }
 
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
             process_name: "Wow.exe".into(),
+            offset_profile: String::new(),
+            poll_interval_ms: 100,
+            auto_reattach: false,
+            last_character: String::new(),
             wow_folder_path: String::new(),
             selected_character: String::new(),
             font_name: "segoeui".into(),
@@ -36,6 +50,7 @@ impl Default for AppConfig {
             auto_translate: false,
             translator_source_lang: String::new(),
             translator_target_lang: "EN-US".into(),
+            muted_senders: Vec::new(),
         }
     }
 }
@@ -105,7 +120,7 @@ pub fn discover_system_fonts() -> Vec<FontEntry> {
             let path = entry.path();
             let is_ttf = path
                 .extension()
-                .map_or(false, |e| e.to_string_lossy().eq_ignore_ascii_case("ttf"));
+                .is_some_and(|e| e.to_string_lossy().eq_ignore_ascii_case("ttf"));
             if is_ttf {
                 let name = path
                     .file_stem()
@@ -120,7 +135,7 @@ pub fn discover_system_fonts() -> Vec<FontEntry> {
         }
     }
 
-    fonts.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    fonts.sort_by_key(|a| a.name.to_lowercase());
     info!("Discovered {} system fonts", fonts.len());
     fonts
 }
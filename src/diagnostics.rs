@@ -0,0 +1,111 @@
+use std::io;
+
+// ─── Attach diagnostics ──────────────────────────────────────────────
+
+/// Why an attach failed and what to do about it, shown in the Process dropdown.
+pub struct AttachDiagnosis {
+    pub problem: String,
+    pub remedy: String,
+    /// Shell command that applies the remedy, if there is one.
+    pub command: Option<String>,
+}
+
+/// Explain a failed attach to `pid`. Returns None when there is nothing more
+/// specific to say than the io error itself.
+#[cfg(target_os = "linux")]
+pub fn diagnose_attach(pid: u32, err: &io::Error) -> Option<AttachDiagnosis> {
+    use std::os::unix::fs::MetadataExt;
+
+    match err.kind() {
+        io::ErrorKind::NotFound => {
+            return Some(AttachDiagnosis {
+                problem: format!("Process {} no longer exists.", pid),
+                remedy: "Start the game and attach again.".into(),
+                command: None,
+            });
+        }
+        io::ErrorKind::PermissionDenied => {}
+        _ => return None,
+    }
+
+    let status = std::fs::read_to_string("/proc/self/status").unwrap_or_default();
+    let our_uid = status_field(&status, "Uid:")
+        .and_then(|v| v.split_whitespace().nth(1)?.parse::<u32>().ok());
+    let has_ptrace_cap = status_field(&status, "CapEff:")
+        .and_then(|v| u64::from_str_radix(v.trim(), 16).ok())
+        .is_some_and(|caps| caps & (1 << CAP_SYS_PTRACE) != 0);
+    let target_uid = std::fs::metadata(format!("/proc/{}", pid)).ok().map(|m| m.uid());
+    let exe = std::env::current_exe()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|_| "wotlk-chat-translator".into());
+
+    // Reading another user's process needs CAP_SYS_PTRACE regardless of Yama.
+    if let (Some(ours), Some(target)) = (our_uid, target_uid) {
+        if ours != target && !has_ptrace_cap {
+            return Some(AttachDiagnosis {
+                problem: format!(
+                    "The game runs as uid {} but this app runs as uid {}.",
+                    target, ours
+                ),
+                remedy: "Run the app as the same user as the game (not via sudo), \
+                         or give it the CAP_SYS_PTRACE capability."
+                    .into(),
+                command: Some(format!("sudo setcap cap_sys_ptrace=eip {}", exe)),
+            });
+        }
+    }
+
+    let scope = std::fs::read_to_string("/proc/sys/kernel/yama/ptrace_scope")
+        .ok()
+        .and_then(|s| s.trim().parse::<u32>().ok());
+    let diagnosis = match scope {
+        Some(1) if !has_ptrace_cap => AttachDiagnosis {
+            problem: "Yama ptrace_scope is 1: only a parent process may read the game's \
+                      memory."
+                .into(),
+            remedy: "Allow reading processes of the same user until reboot (add \
+                     kernel.yama.ptrace_scope = 0 to /etc/sysctl.d/10-ptrace.conf to keep \
+                     it), or give the app CAP_SYS_PTRACE with setcap."
+                .into(),
+            command: Some("sudo sysctl -w kernel.yama.ptrace_scope=0".into()),
+        },
+        Some(2) if !has_ptrace_cap => AttachDiagnosis {
+            problem: "Yama ptrace_scope is 2: only processes with CAP_SYS_PTRACE may read \
+                      the game's memory."
+                .into(),
+            remedy: "Give the app the CAP_SYS_PTRACE capability (repeat after every \
+                     update of the binary)."
+                .into(),
+            command: Some(format!("sudo setcap cap_sys_ptrace=eip {}", exe)),
+        },
+        Some(3) => AttachDiagnosis {
+            problem: "Yama ptrace_scope is 3: reading other processes' memory is disabled \
+                      until reboot."
+                .into(),
+            remedy: "Set kernel.yama.ptrace_scope to 0 or 1 in /etc/sysctl.d/ and reboot.".into(),
+            command: None,
+        },
+        _ => AttachDiagnosis {
+            problem: "Access to the game's memory was denied.".into(),
+            remedy: "Check that no sandbox (Flatpak, Snap, AppArmor/SELinux profile) \
+                     separates the app from the game, and that both run as the same user."
+                .into(),
+            command: None,
+        },
+    };
+    Some(diagnosis)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn diagnose_attach(_pid: u32, _err: &io::Error) -> Option<AttachDiagnosis> {
+    None
+}
+
+/// `CAP_SYS_PTRACE` bit in the capability sets of `/proc/<pid>/status`.
+#[cfg(target_os = "linux")]
+const CAP_SYS_PTRACE: u32 = 19;
+
+#[cfg(target_os = "linux")]
+fn status_field<'a>(status: &'a str, key: &str) -> Option<&'a str> {
+    status.lines().find_map(|line| line.strip_prefix(key))
+}
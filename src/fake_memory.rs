@@ -0,0 +1,549 @@
+use std::collections::HashMap;
+use std::io;
+
+use crate::memory::ProcessMemoryReader;
+use crate::offsets::{self, Offsets};
+
+// ─── Fake WoW memory ─────────────────────────────────────────────────
+//
+// A synthetic 32-bit address space laid out like a 3.3.5a client: the chat ring
+// buffer at `chat_buffer_start` with its next-write index, the player name/realm
+// strings, and an object manager chain reachable from `client_connection`.
+// Unwritten memory is unmapped, so reads behave like a live process with holes.
+//
+// Test-only: the reader tests below, and those of other modules, run against it.
+
+const PAGE_SIZE: usize = 0x1000;
+
+/// Heap area for the fake object manager and objects (any valid 32-bit address).
+const HEAP_BASE: usize = 0x1000_0000;
+const OBJECT_SIZE: usize = 0x100;
+const DESCRIPTOR_SIZE: usize = 0x1300;
+/// `TYPEID_*` values at `object_type_offset`.
+pub const TYPEID_UNIT: u32 = 3;
+pub const TYPEID_PLAYER: u32 = 4;
+pub const TYPEID_GAMEOBJECT: u32 = 5;
+/// Name Store layout: bucket count (mask + 1), offset of the next link in an entry
+/// (the link itself is 4 bytes further), entry size.
+const NAME_BUCKETS: usize = 4;
+pub const NAME_LINK_OFFSET: usize = 0x10;
+const NAME_ENTRY_SIZE: usize = 0x60;
+/// Stored in a bucket or link to end the chain (low bit set).
+const NAME_CHAIN_END: u32 = 1;
+
+pub struct FakeWowMemory {
+    offsets: Offsets,
+    pages: HashMap<usize, Box<[u8; PAGE_SIZE]>>,
+    attached: bool,
+    /// Sequence number of the last pushed message.
+    sequence: u32,
+    /// Slot the next pushed message goes to.
+    next_slot: usize,
+    heap_next: usize,
+    /// Bucket array of the Name Store, once a name was added.
+    name_buckets: Option<usize>,
+    /// Object Manager, once a character is logged in.
+    obj_mgr: Option<usize>,
+}
+
+/// A chat message to write into the fake buffer.
+pub struct FakeMessage {
+    pub message_type: u32,
+    pub sender_guid: u64,
+    pub sender: String,
+    pub text: String,
+    pub channel_number: u32,
+    pub channel_name: String,
+}
+
+impl FakeMessage {
+    pub fn say(sender: &str, text: &str) -> Self {
+        Self {
+            message_type: 1,
+            sender_guid: 0x0600_0000_0000_0001,
+            sender: sender.into(),
+            text: text.into(),
+            channel_number: 0,
+            channel_name: String::new(),
+        }
+    }
+
+    /// A say whose FormattedMessage has no player link, so the sender is only known
+    /// by GUID.
+    pub fn unnamed(sender_guid: u64, text: &str) -> Self {
+        Self {
+            sender_guid,
+            ..Self::say("", text)
+        }
+    }
+
+    /// A MonsterYell from creature `entry` (spawn counter 1).
+    pub fn monster_yell(entry: u32, text: &str) -> Self {
+        Self {
+            message_type: 14,
+            sender_guid: 0xF130_0000_0000_0001 | (entry as u64) << 24,
+            ..Self::say("", text)
+        }
+    }
+
+    pub fn channel(number: u32, name: &str, sender: &str, text: &str) -> Self {
+        Self {
+            message_type: 17,
+            channel_number: number,
+            channel_name: name.into(),
+            ..Self::say(sender, text)
+        }
+    }
+
+    /// FormattedMessage as the client stores it, with player and channel hyperlinks.
+    fn formatted(&self) -> String {
+        let channel = if self.channel_name.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "|Hchannel:channel:{}|h[{}. {}]|h ",
+                self.channel_number, self.channel_number, self.channel_name
+            )
+        };
+        if self.sender.is_empty() {
+            return format!("{}{}", channel, self.text);
+        }
+        format!(
+            "{}|Hplayer:{}|h[{}]|h: {}",
+            channel, self.sender, self.sender, self.text
+        )
+    }
+}
+
+impl FakeWowMemory {
+    /// Empty client: a zeroed chat buffer and next-write index 0, nobody logged in.
+    pub fn new(offsets: Offsets) -> Self {
+        let mut memory = Self {
+            offsets,
+            pages: HashMap::new(),
+            attached: true,
+            sequence: 0,
+            next_slot: 0,
+            heap_next: HEAP_BASE,
+            name_buckets: None,
+            obj_mgr: None,
+        };
+        let buffer_len = offsets::CHAT_BUFFER_SIZE * offsets::CHAT_MESSAGE_STRIDE;
+        memory.write(offsets.chat_buffer_start, &vec![0u8; buffer_len]);
+        memory.write_u32(offsets.chat_buffer_count, 0);
+        memory
+    }
+
+    /// Write `msg` into the next ring slot with the next sequence number and
+    /// advance the next-write index. Returns the slot used.
+    pub fn push_message(&mut self, msg: &FakeMessage) -> usize {
+        let slot = self.next_slot;
+        let base = self.slot_address(slot);
+        self.sequence += 1;
+
+        let mut data = vec![0u8; offsets::CHAT_MESSAGE_STRIDE];
+        put(&mut data, offsets::MSG_SENDER_GUID, &msg.sender_guid.to_le_bytes());
+        put_cstring(&mut data, offsets::MSG_FORMATTED, &msg.formatted());
+        put_cstring(&mut data, offsets::MSG_PLAIN_TEXT, &msg.text);
+        put(&mut data, offsets::MSG_TYPE, &msg.message_type.to_le_bytes());
+        put(&mut data, offsets::MSG_CHANNEL_NUM, &msg.channel_number.to_le_bytes());
+        put(&mut data, offsets::MSG_SEQUENCE, &self.sequence.to_le_bytes());
+        put(&mut data, offsets::MSG_TIMESTAMP, &(1_000 + self.sequence).to_le_bytes());
+        self.write(base, &data);
+
+        self.next_slot = (slot + 1) % offsets::CHAT_BUFFER_SIZE;
+        self.write_u32(self.offsets.chat_buffer_count, self.next_slot as u32);
+        slot
+    }
+
+    /// Zero a slot, as the client does when the chat log is cleared.
+    pub fn clear_slot(&mut self, slot: usize) {
+        let base = self.slot_address(slot);
+        self.write(base, &vec![0u8; offsets::CHAT_MESSAGE_STRIDE]);
+    }
+
+    /// Log a character (a human paladin) in: name/realm strings plus an object
+    /// manager whose list has two other objects ahead of the local player.
+    pub fn set_player(&mut self, name: &str, realm: &str, level: u32, copper: u32) {
+        self.write_cstring(self.offsets.player_name, name, 50);
+        self.write_cstring(self.offsets.realm_name, realm, 50);
+
+        let o = self.offsets;
+        let player_guid: u64 = 0x0000_0000_0000_0042;
+        let obj_mgr = self.object_manager();
+        self.write_u64(obj_mgr + o.local_guid_offset, player_guid);
+
+        let player = self.add_object(player_guid, TYPEID_PLAYER);
+        let descriptor = self.descriptor(player);
+        self.write_u32(descriptor + o.unit_field_level, level);
+        self.write_u32(descriptor + o.unit_field_bytes_0, u32::from_le_bytes([1, 2, 0, 0]));
+        self.write_u32(descriptor + o.player_field_coinage, copper);
+        self.add_object(0xF110_0000_0000_0002, TYPEID_GAMEOBJECT);
+        self.add_object(0xF130_0000_0000_0001, TYPEID_UNIT);
+    }
+
+    /// Add another player in range (after `set_player`).
+    pub fn add_nearby_player(&mut self, guid: u64, level: u32, race: u8, class: u8) {
+        let o = self.offsets;
+        let player = self.add_object(guid, TYPEID_PLAYER);
+        let descriptor = self.descriptor(player);
+        self.write_u32(descriptor + o.unit_field_level, level);
+        self.write_u32(descriptor + o.unit_field_bytes_0, u32::from_le_bytes([race, class, 0, 0]));
+    }
+
+    /// Descriptor address of the object at `object`.
+    pub fn descriptor(&self, object: usize) -> usize {
+        self.read_u32(object + self.offsets.descriptor_ptr_offset) as usize
+    }
+
+    /// The Object Manager, created empty and linked from `client_connection` on first use.
+    fn object_manager(&mut self) -> usize {
+        if let Some(obj_mgr) = self.obj_mgr {
+            return obj_mgr;
+        }
+        let o = self.offsets;
+        let obj_mgr = self.alloc(0x3000);
+        let connection = self.alloc(o.object_manager_offset + 4);
+        self.write_u32(o.client_connection, connection as u32);
+        self.write_u32(connection + o.object_manager_offset, obj_mgr as u32);
+        self.obj_mgr = Some(obj_mgr);
+        obj_mgr
+    }
+
+    /// Put an object with an empty descriptor at the head of the Object Manager list.
+    /// Returns the object address.
+    pub fn add_object(&mut self, guid: u64, type_id: u32) -> usize {
+        let o = self.offsets;
+        let obj_mgr = self.object_manager();
+        let descriptor = self.alloc(DESCRIPTOR_SIZE);
+        let object = self.alloc(OBJECT_SIZE);
+        let first = self.read_u32(obj_mgr + o.first_object_offset);
+        self.write_u64(object + o.object_guid_offset, guid);
+        self.write_u32(object + o.object_type_offset, type_id);
+        self.write_u32(object + o.descriptor_ptr_offset, descriptor as u32);
+        self.write_u32(object + o.next_object_offset, first);
+        self.write_u32(obj_mgr + o.first_object_offset, object as u32);
+        object
+    }
+
+    /// Add `guid` → `name` to the Name Store, creating the table on first use.
+    /// New entries go to the head of their bucket's chain. Returns the entry address.
+    pub fn add_cached_name(&mut self, guid: u64, name: &str) -> usize {
+        let o = self.offsets;
+        let buckets = match self.name_buckets {
+            Some(buckets) => buckets,
+            None => {
+                let buckets = self.alloc(NAME_BUCKETS * 12);
+                for i in 0..NAME_BUCKETS {
+                    self.write_u32(buckets + i * 12, NAME_LINK_OFFSET as u32);
+                    self.write_u32(buckets + i * 12 + 8, NAME_CHAIN_END);
+                }
+                self.write(o.name_store, &[0u8; 0x40]);
+                self.write_u32(o.name_store + o.name_base_offset, buckets as u32);
+                self.write_u32(o.name_store + o.name_mask_offset, NAME_BUCKETS as u32 - 1);
+                self.name_buckets = Some(buckets);
+                buckets
+            }
+        };
+        let low = guid as u32;
+        let head = buckets + (low as usize & (NAME_BUCKETS - 1)) * 12 + 8;
+        let entry = self.alloc(NAME_ENTRY_SIZE);
+        self.write_u32(entry, low);
+        let next = self.read_u32(head);
+        self.write_u32(entry + NAME_LINK_OFFSET + 4, next);
+        self.write_cstring(entry + o.name_string_offset, name, NAME_ENTRY_SIZE - o.name_string_offset);
+        self.write_u32(head, entry as u32);
+        entry
+    }
+
+    /// Point `client_connection` at `ptr` (e.g. 0 or an unmapped address) to
+    /// simulate the login screen or a stale pointer.
+    pub fn set_client_connection(&mut self, ptr: u32) {
+        self.write_u32(self.offsets.client_connection, ptr);
+    }
+
+    pub fn offsets(&self) -> Offsets {
+        self.offsets
+    }
+
+    fn slot_address(&self, slot: usize) -> usize {
+        self.offsets.chat_buffer_start + slot * offsets::CHAT_MESSAGE_STRIDE
+    }
+
+    fn alloc(&mut self, size: usize) -> usize {
+        let address = self.heap_next;
+        self.heap_next += size.next_multiple_of(0x10);
+        self.write(address, &vec![0u8; size]);
+        address
+    }
+
+    pub fn write(&mut self, address: usize, data: &[u8]) {
+        for (i, &byte) in data.iter().enumerate() {
+            let addr = address + i;
+            let page = self
+                .pages
+                .entry(addr / PAGE_SIZE)
+                .or_insert_with(|| Box::new([0u8; PAGE_SIZE]));
+            page[addr % PAGE_SIZE] = byte;
+        }
+    }
+
+    /// Little-endian u32 at `address`; unmapped bytes read as zero.
+    fn read_u32(&self, address: usize) -> u32 {
+        let byte = |addr: usize| {
+            self.pages.get(&(addr / PAGE_SIZE)).map_or(0, |p| p[addr % PAGE_SIZE])
+        };
+        u32::from_le_bytes(std::array::from_fn(|i| byte(address + i)))
+    }
+
+    pub fn write_u32(&mut self, address: usize, value: u32) {
+        self.write(address, &value.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, address: usize, value: u64) {
+        self.write(address, &value.to_le_bytes());
+    }
+
+    fn write_cstring(&mut self, address: usize, s: &str, max_len: usize) {
+        let mut data = vec![0u8; max_len];
+        put_cstring(&mut data, 0, s);
+        self.write(address, &data);
+    }
+}
+
+/// Copy `bytes` into `data` at `offset`.
+fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+    data[offset..offset + bytes.len()].copy_from_slice(bytes);
+}
+
+/// Copy `s` plus a terminating NUL, truncated to the 3000-byte string fields.
+fn put_cstring(data: &mut [u8], offset: usize, s: &str) {
+    let len = s.len().min(offsets::MSG_STRING_MAX_LEN - 1);
+    put(data, offset, &s.as_bytes()[..len]);
+    data[offset + len] = 0;
+}
+
+impl ProcessMemoryReader for FakeWowMemory {
+    fn attach(&mut self, _pid: u32) -> io::Result<()> {
+        self.attached = true;
+        Ok(())
+    }
+
+    fn read_memory(&self, address: usize, size: usize) -> io::Result<Vec<u8>> {
+        if !self.attached {
+            return Err(io::Error::new(io::ErrorKind::NotConnected, "Not attached"));
+        }
+        // Short read at the first unmapped page, like a partial ReadProcessMemory.
+        let mut data = Vec::with_capacity(size);
+        for addr in address..address + size {
+            match self.pages.get(&(addr / PAGE_SIZE)) {
+                Some(page) => data.push(page[addr % PAGE_SIZE]),
+                None => break,
+            }
+        }
+        if data.is_empty() && size > 0 {
+            return Err(io::Error::other(format!(
+                "Address 0x{:X} not mapped",
+                address
+            )));
+        }
+        Ok(data)
+    }
+
+    fn detach(&mut self) -> io::Result<()> {
+        self.attached = false;
+        Ok(())
+    }
+
+    fn is_attached(&self) -> bool {
+        self.attached
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chat::{ChatMessageType, ChatReader};
+    use crate::guid::GuidKind;
+    use crate::player;
+
+    fn poll_texts(chat: &mut ChatReader, memory: &FakeWowMemory) -> Vec<(ChatMessageType, String)> {
+        chat.poll(memory, &memory.offsets())
+            .expect("poll failed")
+            .into_iter()
+            .map(|m| (m.message_type, m.text))
+            .collect()
+    }
+
+    fn texts(chat: &mut ChatReader, memory: &FakeWowMemory) -> Vec<String> {
+        poll_texts(chat, memory).into_iter().map(|(_, t)| t).collect()
+    }
+
+    #[test]
+    fn initial_sync() {
+        let mut memory = FakeWowMemory::new(Offsets::default());
+        memory.push_message(&FakeMessage::say("Alice", "one"));
+        memory.push_message(&FakeMessage::channel(2, "Trade", "Bob", "two"));
+
+        let messages = ChatReader::new().poll(&memory, &memory.offsets()).unwrap();
+        assert_eq!(messages.len(), 2);
+        let (say, channel) = (&messages[0], &messages[1]);
+        assert_eq!((say.text.as_str(), say.sender_name.as_str()), ("one", "Alice"));
+        assert_eq!(channel.message_type, ChatMessageType::Channel);
+        assert_eq!(channel.channel_name, "2. Trade");
+    }
+
+    #[test]
+    fn incremental_poll() {
+        let mut memory = FakeWowMemory::new(Offsets::default());
+        let mut chat = ChatReader::new();
+        memory.push_message(&FakeMessage::say("Alice", "old"));
+        poll_texts(&mut chat, &memory);
+
+        assert_eq!(poll_texts(&mut chat, &memory), []);
+
+        memory.push_message(&FakeMessage::say("Alice", "new 1"));
+        memory.push_message(&FakeMessage::say("Alice", "new 2"));
+        assert_eq!(texts(&mut chat, &memory), ["new 1", "new 2"]);
+    }
+
+    #[test]
+    fn ring_wrap_around() {
+        let mut memory = FakeWowMemory::new(Offsets::default());
+        let mut chat = ChatReader::new();
+        for i in 0..50 {
+            memory.push_message(&FakeMessage::say("Alice", &format!("msg {}", i)));
+        }
+        poll_texts(&mut chat, &memory);
+
+        // 70 more: the ring holds 60, so the first 10 of them are overwritten unread.
+        for i in 50..120 {
+            memory.push_message(&FakeMessage::say("Alice", &format!("msg {}", i)));
+        }
+        let messages = poll_texts(&mut chat, &memory);
+        assert_eq!(messages.len(), 61);
+        assert_eq!(messages[0].0, ChatMessageType::System);
+        assert!(messages[0].1.starts_with("10 messages lost"), "{:?}", messages[0].1);
+        assert_eq!(messages[1].1, "msg 60");
+        assert_eq!(messages[60].1, "msg 119");
+    }
+
+    #[test]
+    fn cleared_slots() {
+        let mut memory = FakeWowMemory::new(Offsets::default());
+        let first = memory.push_message(&FakeMessage::say("Alice", "one"));
+        let second = memory.push_message(&FakeMessage::say("Alice", "two"));
+        memory.push_message(&FakeMessage::say("Alice", "three"));
+
+        // A slot cleared before the first sync is skipped.
+        memory.clear_slot(second);
+        let mut chat = ChatReader::new();
+        assert_eq!(texts(&mut chat, &memory), ["one", "three"]);
+
+        // Clearing already read slots (chat log cleared) yields nothing new.
+        memory.clear_slot(first);
+        assert_eq!(poll_texts(&mut chat, &memory), []);
+
+        memory.push_message(&FakeMessage::say("Alice", "after clear"));
+        assert_eq!(texts(&mut chat, &memory), ["after clear"]);
+    }
+
+    #[test]
+    fn player_info() {
+        let mut memory = FakeWowMemory::new(Offsets::default());
+        memory.set_player("Alice", "Icecrown", 80, 123_456);
+        let info = player::read_player_info(&memory, &memory.offsets()).expect("no player info");
+        assert_eq!(
+            (info.name.as_str(), info.realm.as_str(), info.level),
+            ("Alice", "Icecrown", 80)
+        );
+        assert_eq!((info.gold(), info.silver(), info.copper_rem()), (12, 34, 56));
+    }
+
+    #[test]
+    fn name_store() {
+        let mut memory = FakeWowMemory::new(Offsets::default());
+        // Two players in the same bucket, and a creature with the same low GUID.
+        memory.add_cached_name(0x0000_0000_0000_0005, "Alice");
+        memory.add_cached_name(0x0000_0000_0000_0009, "Bob");
+        memory.push_message(&FakeMessage::unnamed(0x0000_0000_0000_0005, "one"));
+        memory.push_message(&FakeMessage::unnamed(0x0000_0000_0000_0009, "two"));
+        memory.push_message(&FakeMessage::unnamed(0x0000_0000_0000_000D, "three"));
+        memory.push_message(&FakeMessage::unnamed(0xF130_0000_0000_0005, "four"));
+
+        let mut chat = ChatReader::new();
+        let senders = |chat: &mut ChatReader, memory: &FakeWowMemory| -> Vec<String> {
+            let messages = chat.poll(memory, &memory.offsets()).unwrap();
+            messages.into_iter().map(|m| m.sender_name).collect()
+        };
+        assert_eq!(senders(&mut chat, &memory), ["Alice", "Bob", "", ""]);
+
+        // A name the client learns later is found on the next message.
+        memory.add_cached_name(0x0000_0000_0000_000D, "Carol");
+        memory.push_message(&FakeMessage::unnamed(0x0000_0000_0000_000D, "five"));
+        assert_eq!(senders(&mut chat, &memory), ["Carol"]);
+    }
+
+    #[test]
+    fn nearby_players() {
+        let mut memory = FakeWowMemory::new(Offsets::default());
+        memory.set_player("Alice", "Icecrown", 80, 0);
+        memory.add_nearby_player(0x0000_0000_0000_0007, 72, 11, 7);
+        let players = player::read_nearby_players(&memory, &memory.offsets()).unwrap();
+        let found: Vec<(u64, String)> = players.iter().map(|(g, d)| (*g, d.summary())).collect();
+        // The creature and game object in the list are skipped.
+        assert_eq!(
+            found,
+            [
+                (0x7, "Level 72 Draenei Shaman".to_string()),
+                (0x42, "Level 80 Human Paladin".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn sender_guids() {
+        let mut memory = FakeWowMemory::new(Offsets::default());
+        memory.push_message(&FakeMessage::monster_yell(36597, "yell"));
+        memory.push_message(&FakeMessage::say("Alice", "say"));
+        // A player GUID in a monster message has no NPC entry.
+        memory.push_message(&FakeMessage {
+            message_type: 14,
+            ..FakeMessage::say("Alice", "player yell")
+        });
+        let messages = ChatReader::new().poll(&memory, &memory.offsets()).unwrap();
+        let decoded: Vec<(GuidKind, Option<u32>)> = messages
+            .iter()
+            .map(|m| (m.sender_guid.kind(), m.npc_entry()))
+            .collect();
+        assert_eq!(
+            decoded,
+            [
+                (GuidKind::Creature, Some(36597)),
+                (GuidKind::Player, None),
+                (GuidKind::Player, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_pointers() {
+        let mut memory = FakeWowMemory::new(Offsets::default());
+        let offsets = memory.offsets();
+        assert!(player::read_player_info(&memory, &offsets).is_none(), "login screen");
+
+        memory.set_player("Alice", "Icecrown", 80, 100);
+        for ptr in [0, 0x1, 0x7FFF_FFF0, 0x2000_0000] {
+            memory.set_client_connection(ptr);
+            let info = player::read_player_info(&memory, &offsets)
+                .unwrap_or_else(|| panic!("name lost with client connection 0x{:X}", ptr));
+            assert_eq!((info.level, info.copper), (0, 0), "through pointer 0x{:X}", ptr);
+        }
+
+        // A chat buffer address that is not mapped at all must fail the poll.
+        let mut bad = offsets;
+        bad.chat_buffer_start = 0x2000_0000;
+        bad.chat_buffer_count = 0x2000_0000;
+        assert!(ChatReader::new().poll(&memory, &bad).is_err());
+    }
+}
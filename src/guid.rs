@@ -0,0 +1,108 @@
+use std::fmt;
+
+// ─── GUIDs ───────────────────────────────────────────────────────────
+//
+// 3.3.5 object GUIDs carry their type in the high 16 bits. Creatures, pets,
+// vehicles and game objects also carry their template entry:
+//
+//   0xF130 EEEEEE CCCCCC   high type, 24-bit entry, 24-bit spawn counter
+//
+// Players have a zero top nibble and only a counter.
+
+/// What kind of object a GUID refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuidKind {
+    Player,
+    Creature,
+    Pet,
+    Vehicle,
+    GameObject,
+    Item,
+    Other,
+}
+
+impl GuidKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            GuidKind::Player => "Player",
+            GuidKind::Creature => "NPC",
+            GuidKind::Pet => "Pet",
+            GuidKind::Vehicle => "Vehicle",
+            GuidKind::GameObject => "Object",
+            GuidKind::Item => "Item",
+            GuidKind::Other => "Other",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Guid(pub u64);
+
+impl Guid {
+    pub fn raw(self) -> u64 {
+        self.0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    fn high(self) -> u16 {
+        (self.0 >> 48) as u16
+    }
+
+    pub fn kind(self) -> GuidKind {
+        match self.high() {
+            _ if self.is_empty() => GuidKind::Other,
+            0xF110 | 0xF120 => GuidKind::GameObject,
+            0xF130 => GuidKind::Creature,
+            0xF140 => GuidKind::Pet,
+            0xF150 => GuidKind::Vehicle,
+            0x4000 => GuidKind::Item,
+            high if high >> 12 == 0 => GuidKind::Player,
+            _ => GuidKind::Other,
+        }
+    }
+
+    /// Template entry (creature or game object ID) for GUID kinds that have one.
+    pub fn entry(self) -> Option<u32> {
+        match self.kind() {
+            GuidKind::Creature | GuidKind::Pet | GuidKind::Vehicle | GuidKind::GameObject => {
+                Some(((self.0 >> 24) & 0x00FF_FFFF) as u32)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{:016X}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_and_entry() {
+        let cases = [
+            // Lich King (entry 36597), spawn counter 0x1234.
+            (0xF130_008E_F500_1234, GuidKind::Creature, Some(36597)),
+            (0xF140_0001_2300_0042, GuidKind::Pet, Some(0x123)),
+            (0xF150_0070_8300_0001, GuidKind::Vehicle, Some(0x7083)),
+            (0xF110_0002_F400_0010, GuidKind::GameObject, Some(0x2F4)),
+            (0x0000_0000_0000_0042, GuidKind::Player, None),
+            (0x0700_0000_0123_4567, GuidKind::Player, None),
+            (0x4000_0000_0000_1234, GuidKind::Item, None),
+            (0xF160_0000_0000_0001, GuidKind::Other, None),
+            (0, GuidKind::Other, None),
+        ];
+        for (raw, kind, entry) in cases {
+            let guid = Guid(raw);
+            assert_eq!(guid.kind(), kind, "{}", guid);
+            assert_eq!(guid.entry(), entry, "{}", guid);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+// ─── Hex dump formatting ─────────────────────────────────────────────
+
+pub const BYTES_PER_ROW: usize = 16;
+
+/// Hex of `bytes` separated by spaces, e.g. "4A 61 69 6E".
+pub fn hex_bytes(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// One dump row: address, up to 16 hex bytes (`??` past the readable end) and ASCII.
+pub fn format_row(address: usize, bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(BYTES_PER_ROW * 3 + 1);
+    let mut ascii = String::with_capacity(BYTES_PER_ROW);
+    for i in 0..BYTES_PER_ROW {
+        if i == BYTES_PER_ROW / 2 {
+            hex.push(' ');
+        }
+        match bytes.get(i) {
+            Some(&b) => {
+                hex.push_str(&format!("{:02X} ", b));
+                ascii.push(if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' });
+            }
+            None => {
+                hex.push_str("?? ");
+                ascii.push(' ');
+            }
+        }
+    }
+    format!("{:08X}  {} |{}|", address, hex, ascii)
+}
+
+/// Parse an address typed by the user: hex with or without `0x`.
+pub fn parse_address(input: &str) -> Option<usize> {
+    let input = input.trim();
+    let digits = input
+        .strip_prefix("0x")
+        .or_else(|| input.strip_prefix("0X"))
+        .unwrap_or(input);
+    usize::from_str_radix(digits, 16).ok()
+}
@@ -1,17 +1,32 @@
 mod chat;
+mod clients;
 mod clipboard;
 mod config;
+mod diagnostics;
+#[cfg(test)]
+mod fake_memory;
 mod glossary;
+mod guid;
+mod hexview;
 mod memory;
+mod names;
 mod offsets;
 mod player;
+mod pointer;
+mod polling;
+mod profiles;
+mod recording;
+mod scanner;
+mod session;
+mod signature;
+mod snapshot;
 mod translation;
 mod wtf_parser;
 
 use std::collections::HashMap;
 use std::num::NonZeroU32;
 
-use chat::{ChatMessage, ChatReader, ChatTab, TextSegment};
+use chat::{ChatMessage, ChatTab, TextSegment};
 use translation::{TranslationEntry, TranslationRequest, TranslationResponse, TranslationService};
 use glow::HasContext;
 use glutin::config::ConfigTemplateBuilder;
@@ -24,14 +39,24 @@ use imgui_glow_renderer::AutoRenderer;
 use imgui_winit_support::{HiDpiMode, WinitPlatform};
 use log::{error, info, warn};
 use raw_window_handle::HasWindowHandle;
-use sysinfo::System;
 use winit::application::ApplicationHandler;
 use winit::event::WindowEvent;
 use winit::event_loop::EventLoop;
 use winit::window::{Window, WindowAttributes};
 
 const MAX_MESSAGES: usize = 500;
+/// How often the auto-reattach watchdog looks for the game while detached.
+const WATCHDOG_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3);
+const MAX_RECONNECT_HISTORY: usize = 20;
+/// Command line shown in the process picker beyond this is cut; the tooltip has it all.
+const MAX_COMMAND_LINE_CHARS: usize = 60;
+/// Players remembered for sender colors before the cache is cleared.
+const MAX_UNIT_DETAILS: usize = 5000;
+/// How often the memory inspector re-reads while "Live" is on.
+const INSPECTOR_REFRESH: std::time::Duration = std::time::Duration::from_millis(250);
+const HEX_VIEW_MAX_LEN: i32 = 4096;
 const THEMES: &[&str] = &["Dark", "Light", "Classic"];
+const REPLAY_SPEEDS: &[(&str, f32)] = &[("1x", 1.0), ("2x", 2.0), ("5x", 5.0), ("10x", 10.0)];
 
 #[derive(PartialEq, Clone, Copy)]
 enum AppBarDropdown {
@@ -40,6 +65,13 @@ enum AppBarDropdown {
     DebugTools,
 }
 
+/// "Whispers: Name" or "Messages from Name" window opened from the sender menu.
+struct SenderWindow {
+    name: String,
+    whispers_only: bool,
+    open: bool,
+}
+
 // ─── App State ───────────────────────────────────────────────────────
 
 struct AppState {
@@ -51,18 +83,33 @@ struct AppState {
 
     // Runtime state
     status_text: String,
-    attached_pid: Option<u32>,
-    reader: Box<dyn memory::ProcessMemoryReader>,
-    chat_reader: ChatReader,
+    /// Explanation of the last failed attach, shown in the Process dropdown.
+    attach_diagnosis: Option<diagnostics::AttachDiagnosis>,
+    sessions: Vec<session::ClientSession>,
+    /// Session shown in the status bar and used by the debug tools.
+    current_session: usize,
+    watchdog_suspended: bool,
+    /// Characters whose client failed a read; the watchdog attaches to them again.
+    lost_characters: Vec<String>,
+    watchdog_last_check: std::time::Instant,
+    /// Lists game clients for the watchdog off the UI thread.
+    client_watcher: clients::ClientWatcher,
+    reconnect_history: Vec<String>,
+    game_clients: Vec<clients::GameClient>,
+    profiles: Vec<profiles::OffsetProfile>,
     chat_messages: Vec<ChatMessage>,
     chat_tabs: Vec<ChatTab>,
     active_tab: usize,
     had_new_messages: bool,
-    search_text: String,
+    replay_speed_index: usize,
     clipboard: Option<clipboard::ClipboardHelper>,
-
-    // Player info (read from memory each frame)
-    player_info: Option<player::PlayerInfo>,
+    /// Show only messages from this client (session label); empty = all clients.
+    chat_source_filter: String,
+    /// Show only messages from this player; empty = everyone.
+    chat_sender_filter: String,
+    sender_windows: Vec<SenderWindow>,
+    /// Level, race and class by GUID of players seen in any client's Object Manager.
+    unit_details: HashMap<u64, player::UnitDetails>,
 
     // Translation
     translation_service: Option<TranslationService>,
@@ -89,6 +136,43 @@ struct AppState {
     translator_pending: bool,
     translator_error: String,
 
+    // Memory scanner
+    scanner_window_open: bool,
+    scanner: scanner::MemoryScanner,
+    /// First/next scan or refresh in progress; `scanner` is handed back when it is done.
+    scanner_running: Option<scanner::RunningScan>,
+    scanner_type_index: usize,
+    scanner_filter_index: usize,
+    scanner_input: String,
+    scanner_status: String,
+    /// Addresses marked in the scanner for saving into the offset profile, each with
+    /// the index of its field in `Offsets::ADDRESS_FIELDS`.
+    scanner_candidates: Vec<(usize, usize)>,
+
+    // Memory inspector (hex viewer + chat slot)
+    inspector_window_open: bool,
+    hex_address_input: String,
+    hex_length: i32,
+    /// Address and bytes of the last hex view read (short if memory ended).
+    hex_base: usize,
+    hex_bytes: Vec<u8>,
+    hex_error: String,
+    inspector_slot: i32,
+    slot_bytes: Vec<u8>,
+    /// Name, path and value (or error) of each `[[profile.pointer]]`.
+    pointer_values: Vec<(String, String, Result<String, String>)>,
+    inspector_live: bool,
+    inspector_last_read: std::time::Instant,
+
+    // Offset discovery wizard
+    discovery_window_open: bool,
+    discovery_phrase: String,
+    discovery_candidates: Vec<chat::BufferCandidate>,
+    /// Phrase scan in progress; its candidates replace `discovery_candidates`.
+    discovery_running: Option<scanner::RunningScan<Vec<chat::BufferCandidate>>>,
+    discovery_selected: usize,
+    discovery_status: String,
+
     // Glossary
     glossary: glossary::Glossary,
     glossary_editor_open: bool,
@@ -99,6 +183,279 @@ struct AppState {
     glossary_editor_status: String,
 }
 
+impl AppState {
+    /// Attach to `pid` as an additional session, pick an offset profile for its client
+    /// build and refine the chat addresses from signatures.
+    fn attach_process(&mut self, pid: u32, exe: Option<&std::path::Path>) -> bool {
+        if let Some(idx) = self.sessions.iter().position(|s| s.pid == pid) {
+            self.current_session = idx;
+            self.status_text = format!("Already attached to PID {}", pid);
+            return false;
+        }
+        let mut reader = memory::create_reader();
+        if let Err(e) = reader.attach(pid) {
+            error!("Failed to attach to PID={}: {}", pid, e);
+            self.status_text = format!("Failed to attach: {}", e);
+            self.attach_diagnosis = diagnostics::diagnose_attach(pid, &e);
+            if let Some(ref d) = self.attach_diagnosis {
+                warn!("{} {}", d.problem, d.remedy);
+            }
+            return false;
+        }
+        self.attach_diagnosis = None;
+
+        let client_version = profiles::detect_client_version(&*reader, exe);
+        let active_profile =
+            profiles::select_profile(&self.profiles, &self.config.offset_profile, client_version);
+        let profile = active_profile.map(|idx| &self.profiles[idx]);
+        let base = profile.map(|p| p.offsets).unwrap_or_default();
+        let profile_name = profile.map_or("built-in", |p| p.name.as_str());
+        info!("Using offset profile '{}'", profile_name);
+
+        let (offsets, signatures) = if profile.is_none_or(|p| p.use_signatures) {
+            let pinned = profile.map_or(&[][..], |p| &p.pinned[..]);
+            let scan = signature::resolve_offsets(&*reader, &base, pinned);
+            (scan.offsets, format!(", signatures {}/{}", scan.matched, scan.total))
+        } else {
+            (base, String::new())
+        };
+
+        self.status_text = format!(
+            "Attached to {} (PID: {}, {}{})",
+            self.config.process_name, pid, profile_name, signatures,
+        );
+        let mut session =
+            session::ClientSession::start(pid, reader, offsets, self.config.poll_interval_ms);
+        session.active_profile = active_profile;
+        session.client_version = client_version;
+        self.sessions.push(session);
+        self.current_session = self.sessions.len() - 1;
+        self.config.save();
+        info!("Successfully attached to PID={}", pid);
+        true
+    }
+
+    fn current(&self) -> Option<&session::ClientSession> {
+        self.sessions.get(self.current_session)
+    }
+
+    /// Detach session `idx` and keep `current_session` pointing at a live session.
+    fn remove_session(&mut self, idx: usize) {
+        let mut session = self.sessions.remove(idx);
+        session.detach();
+        if self.current_session >= idx && self.current_session > 0 {
+            self.current_session -= 1;
+        }
+    }
+
+    /// Replace every session with a snapshot or recording replay.
+    fn start_replay_session(
+        &mut self,
+        pid: u32,
+        reader: Box<dyn memory::ProcessMemoryReader + Send>,
+        offsets: offsets::Offsets,
+    ) {
+        while !self.sessions.is_empty() {
+            self.remove_session(0);
+        }
+        self.sessions.push(session::ClientSession::start(
+            pid,
+            reader,
+            offsets,
+            self.config.poll_interval_ms,
+        ));
+        self.current_session = 0;
+        self.chat_messages.clear();
+    }
+
+    /// Save `address` as `field` of offset profile `profile` and restart every session
+    /// using that profile with it. The field is pinned, otherwise the signature scan on
+    /// the next attach could replace the saved address.
+    fn set_profile_address(&mut self, profile: usize, field: &str, address: usize) {
+        let entry = &mut self.profiles[profile];
+        if let Some(slot) = entry.offsets.address_field_mut(field) {
+            *slot = address;
+        }
+        if !entry.pinned.iter().any(|p| p == field) {
+            entry.pinned.push(field.to_string());
+        }
+        profiles::save(&self.profiles);
+        for session in &mut self.sessions {
+            if session.active_profile != Some(profile) {
+                continue;
+            }
+            if let Some(slot) = session.offsets.address_field_mut(field) {
+                *slot = address;
+            }
+            session.polling.start(session.offsets);
+            session.recording = false;
+        }
+        // Restarted sessions load the whole chat buffer again.
+        self.chat_messages.clear();
+        info!(
+            "{}=0x{:08X} saved to offset profile '{}'",
+            field, address, self.profiles[profile].name
+        );
+    }
+
+    /// Re-list running game clients, reading character names with the offsets of the
+    /// configured profile (before attach the client build is not known yet).
+    fn refresh_game_clients(&mut self) {
+        self.game_clients =
+            clients::list_game_clients(&self.config.process_name, &self.listing_offsets());
+    }
+
+    /// Offsets used to read character names while listing clients.
+    fn listing_offsets(&self) -> offsets::Offsets {
+        profiles::select_profile(&self.profiles, &self.config.offset_profile, None)
+            .map(|idx| self.profiles[idx].offsets)
+            .unwrap_or_default()
+    }
+
+    /// Attach to `game_clients[idx]` and remember its character for next time. The
+    /// first attach also fills in the WoW folder so the WTF scan needs no browsing.
+    fn attach_client(&mut self, idx: usize) -> bool {
+        let pid = self.game_clients[idx].pid;
+        let exe = self.game_clients[idx].exe.clone();
+        let character = self.game_clients[idx].character.clone();
+        if let (true, Some(dir)) = (
+            self.config.wow_folder_path.is_empty(),
+            &self.game_clients[idx].game_dir,
+        ) {
+            info!("WoW folder set from the running client: {}", dir.display());
+            self.config.wow_folder_path = dir.to_string_lossy().into_owned();
+        }
+        if !self.attach_process(pid, exe.as_deref()) {
+            return false;
+        }
+        if let Some(character) = character {
+            self.config.last_character = character;
+            self.config.save();
+        }
+        true
+    }
+
+    /// Hand the memory scanner to a background thread running `scan`; it comes back
+    /// through `scanner_running` with the status line `scan` returns.
+    fn start_scan<F>(&mut self, scan: F)
+    where
+        F: FnOnce(&mut scanner::MemoryScanner) -> String + Send + 'static,
+    {
+        let scanner = std::mem::take(&mut self.scanner);
+        self.scanner_running = Some(scanner::RunningScan::start(scanner, scan));
+        self.scanner_status = "Scanning...".into();
+    }
+
+    /// Re-read the hex view and the inspected chat slot from the current session.
+    fn refresh_inspector(&mut self) {
+        self.inspector_last_read = std::time::Instant::now();
+        let Some(session) = self.sessions.get(self.current_session) else {
+            return;
+        };
+        // Lock per read so the polling thread is not held up by the whole refresh.
+        let reader = &session.reader;
+
+        match hexview::parse_address(&self.hex_address_input) {
+            Some(address) => {
+                self.hex_base = address;
+                let read =
+                    polling::lock_reader(reader).read_memory(address, self.hex_length as usize);
+                match read {
+                    Ok(bytes) => {
+                        self.hex_error = if bytes.len() < self.hex_length as usize {
+                            format!("Readable up to 0x{:08X}", address + bytes.len())
+                        } else {
+                            String::new()
+                        };
+                        self.hex_bytes = bytes;
+                    }
+                    Err(e) => {
+                        self.hex_bytes.clear();
+                        self.hex_error = format!("Read error: {}", e);
+                    }
+                }
+            }
+            None => {
+                self.hex_bytes.clear();
+                self.hex_error = "Enter a hex address".into();
+            }
+        }
+
+        let slot_addr = chat::slot_address(&session.offsets, self.inspector_slot as usize);
+        self.slot_bytes = polling::lock_reader(reader)
+            .read_memory(slot_addr, offsets::CHAT_MESSAGE_STRIDE)
+            .unwrap_or_default();
+
+        let pointers = session
+            .active_profile
+            .and_then(|p| self.profiles.get(p))
+            .map(|p| p.pointers.as_slice())
+            .unwrap_or_default();
+        self.pointer_values = pointers
+            .iter()
+            .map(|p| {
+                let value = p.read(&**polling::lock_reader(reader)).map_err(|e| e.to_string());
+                (p.name.clone(), p.path.to_string(), value)
+            })
+            .collect();
+    }
+
+    /// With auto-reattach on, look for the game every `WATCHDOG_INTERVAL` while
+    /// nothing is attached or a client was lost, and attach when it (re)appears. The
+    /// client list is built by `client_watcher`; only the attach runs here.
+    fn run_watchdog(&mut self) {
+        let listed = self.client_watcher.try_result();
+        if !self.config.auto_reattach
+            || self.watchdog_suspended
+            || (!self.sessions.is_empty() && self.lost_characters.is_empty())
+        {
+            return;
+        }
+        if self.watchdog_last_check.elapsed() >= WATCHDOG_INTERVAL {
+            self.watchdog_last_check = std::time::Instant::now();
+            let offsets = self.listing_offsets();
+            self.client_watcher.request(&self.config.process_name, offsets);
+        }
+        let Some(listed) = listed else {
+            return;
+        };
+
+        self.game_clients = listed;
+        let wanted: Vec<usize> = if self.lost_characters.is_empty() {
+            clients::auto_select(&self.game_clients, &self.config.last_character)
+                .into_iter()
+                .collect()
+        } else {
+            self.game_clients
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| {
+                    c.character.as_ref().is_some_and(|name| self.lost_characters.contains(name))
+                        && !self.sessions.iter().any(|s| s.pid == c.pid)
+                })
+                .map(|(idx, _)| idx)
+                .collect()
+        };
+        for idx in wanted {
+            let pid = self.game_clients[idx].pid;
+            info!("Watchdog: found '{}' (PID={}), reattaching", self.config.process_name, pid);
+            if !self.attach_client(idx) {
+                continue;
+            }
+            let label = self.game_clients[idx]
+                .character
+                .clone()
+                .unwrap_or_else(|| format!("PID {}", pid));
+            self.lost_characters.retain(|name| *name != label);
+            let entry = format!("{} {} (PID {})", chrono::Local::now().format("%H:%M:%S"), label, pid);
+            self.reconnect_history.push(entry);
+            if self.reconnect_history.len() > MAX_RECONNECT_HISTORY {
+                self.reconnect_history.remove(0);
+            }
+        }
+    }
+}
+
 // ─── App (owns GL + imgui state) ─────────────────────────────────────
 
 struct App {
@@ -184,16 +541,26 @@ impl App {
                 open_dropdown: None,
                 config: cfg,
                 status_text: String::from("Not attached"),
-                attached_pid: None,
-                reader: memory::create_reader(),
-                chat_reader: ChatReader::new(),
-                player_info: None,
+                attach_diagnosis: None,
+                sessions: Vec::new(),
+                current_session: 0,
+                watchdog_suspended: false,
+                lost_characters: Vec::new(),
+                watchdog_last_check: std::time::Instant::now(),
+                client_watcher: clients::ClientWatcher::start(),
+                reconnect_history: Vec::new(),
+                game_clients: Vec::new(),
+                profiles: profiles::load(),
                 chat_messages: Vec::new(),
                 chat_tabs,
                 active_tab: 0,
                 had_new_messages: false,
-                search_text: String::new(),
+                replay_speed_index: 0,
                 clipboard: clipboard::ClipboardHelper::new(),
+                chat_source_filter: String::new(),
+                chat_sender_filter: String::new(),
+                sender_windows: Vec::new(),
+                unit_details: HashMap::new(),
                 translation_service,
                 translation_rx,
                 translations: HashMap::new(),
@@ -213,6 +580,31 @@ impl App {
                 translator_output: String::new(),
                 translator_pending: false,
                 translator_error: String::new(),
+                scanner_window_open: false,
+                scanner: scanner::MemoryScanner::new(),
+                scanner_running: None,
+                scanner_type_index: 1,
+                scanner_filter_index: 0,
+                scanner_input: String::new(),
+                scanner_status: String::new(),
+                scanner_candidates: Vec::new(),
+                inspector_window_open: false,
+                hex_address_input: String::new(),
+                hex_length: 256,
+                hex_base: 0,
+                hex_bytes: Vec::new(),
+                hex_error: String::new(),
+                inspector_slot: 0,
+                slot_bytes: Vec::new(),
+                pointer_values: Vec::new(),
+                inspector_live: true,
+                inspector_last_read: std::time::Instant::now(),
+                discovery_window_open: false,
+                discovery_phrase: String::new(),
+                discovery_candidates: Vec::new(),
+                discovery_running: None,
+                discovery_selected: 0,
+                discovery_status: String::new(),
                 glossary: glossary::Glossary::load(),
                 glossary_editor_open: false,
                 glossary_edit_keys: String::new(),
@@ -500,11 +892,17 @@ impl ApplicationHandler for App {
                 let state = &mut self.state;
                 state.had_new_messages = false;
 
-                if state.attached_pid.is_some() {
-                    match state.chat_reader.poll(&*state.reader) {
-                        Ok(new_msgs) => {
-                            if !new_msgs.is_empty() {
+                // Drain chat/player updates from each session's polling thread (non-blocking)
+                let mut failed_sessions = Vec::new();
+                for (session_idx, session) in state.sessions.iter_mut().enumerate() {
+                    while let Some(event) = session.next_event() {
+                        match event {
+                            polling::PollEvent::Messages(mut new_msgs) => {
                                 state.had_new_messages = true;
+                                let source = session.label();
+                                for msg in &mut new_msgs {
+                                    msg.source.clone_from(&source);
+                                }
                                 append_chat_history(&new_msgs);
 
                                 // Auto-translate new messages before adding to history
@@ -535,20 +933,36 @@ impl ApplicationHandler for App {
                                     state.chat_messages.drain(..drain);
                                 }
                             }
-                        }
-                        Err(e) => {
-                            error!("Poll failed, auto-detaching: {}", e);
-                            state.status_text = format!("Read error (detached): {}", e);
-                            state.attached_pid = None;
-                            let _ = state.reader.detach();
-                            state.chat_reader.reset();
+                            polling::PollEvent::PlayerInfo(info) => {
+                                session.set_player_info(info);
+                            }
+                            polling::PollEvent::NearbyPlayers(players) => {
+                                if state.unit_details.len() + players.len() > MAX_UNIT_DETAILS {
+                                    state.unit_details.clear();
+                                }
+                                state.unit_details.extend(players);
+                            }
+                            polling::PollEvent::Failed(e) => {
+                                error!("Poll failed for {}, auto-detaching: {}", session.label(), e);
+                                state.status_text =
+                                    format!("Read error ({} detached): {}", session.label(), e);
+                                failed_sessions.push(session_idx);
+                            }
+                            polling::PollEvent::RecordingStopped => {
+                                session.recording = false;
+                                state.status_text = "Recording stopped (write error)".into();
+                            }
                         }
                     }
-                    // Read player info (name, realm, level, money) from memory.
-                    state.player_info = player::read_player_info(&*state.reader);
-                } else {
-                    state.player_info = None;
                 }
+                for idx in failed_sessions.into_iter().rev() {
+                    let character = state.sessions[idx].character.clone();
+                    if !character.is_empty() && !state.lost_characters.contains(&character) {
+                        state.lost_characters.push(character);
+                    }
+                    state.remove_session(idx);
+                }
+                state.run_watchdog();
 
                 // Poll translation responses (non-blocking)
                 if let Some(ref rx) = state.translation_rx {
@@ -599,7 +1013,7 @@ impl ApplicationHandler for App {
 
                 let ui = imgui.frame();
                 let state = &mut self.state;
-                let is_attached = state.attached_pid.is_some();
+                let is_attached = !state.sessions.is_empty();
 
                 // ── AppBar ────────────────────────────────────────
                 let mut appbar_height = 35.0_f32;
@@ -650,7 +1064,8 @@ impl ApplicationHandler for App {
                         }
 
                         // Status text + player info on the right
-                        let player_info_width = if let Some(ref pi) = state.player_info {
+                        let player_info = state.current().and_then(|s| s.player_info.as_ref());
+                        let player_info_width = if let Some(pi) = player_info {
                             let name_realm = format!("{}  -  {}", pi.name, pi.realm);
                             let level_str = format!("  Lv.{}  ", pi.level);
                             let gold_str = format!("{}", pi.gold());
@@ -668,29 +1083,47 @@ impl ApplicationHandler for App {
                         } else {
                             0.0
                         };
-                        let status_w = ui.calc_text_size(&state.status_text)[0];
+                        let reconnect_label = if state.reconnect_history.is_empty() {
+                            String::new()
+                        } else {
+                            format!("[reconnected x{}] ", state.reconnect_history.len())
+                        };
+                        let status_w = ui.calc_text_size(&state.status_text)[0]
+                            + ui.calc_text_size(&reconnect_label)[0];
                         let total_right = status_w + player_info_width + 24.0;
                         ui.same_line_with_pos(display_size[0] - total_right);
+                        if !reconnect_label.is_empty() {
+                            ui.text_colored([0.5, 0.7, 0.9, 1.0], &reconnect_label);
+                            if ui.is_item_hovered() {
+                                ui.tooltip(|| {
+                                    ui.text("Auto-reattach history:");
+                                    for entry in &state.reconnect_history {
+                                        ui.text(entry);
+                                    }
+                                });
+                            }
+                            ui.same_line_with_spacing(0.0, 0.0);
+                        }
                         ui.text_colored([0.7, 0.7, 0.3, 1.0], &state.status_text);
 
-                        if let Some(ref pi) = state.player_info {
+                        if let Some(pi) = player_info {
                             ui.same_line();
-                            ui.text_colored([1.0, 1.0, 1.0, 0.9], &format!("  {}  -  {}", pi.name, pi.realm));
+                            ui.text_colored([1.0, 1.0, 1.0, 0.9], format!("  {}  -  {}", pi.name, pi.realm));
                             ui.same_line();
-                            ui.text_colored([0.5, 0.8, 1.0, 1.0], &format!("Lv.{}", pi.level));
+                            ui.text_colored([0.5, 0.8, 1.0, 1.0], format!("Lv.{}", pi.level));
                             ui.same_line();
                             // Gold (yellow)
-                            ui.text_colored([1.0, 0.84, 0.0, 1.0], &format!("  {}", pi.gold()));
+                            ui.text_colored([1.0, 0.84, 0.0, 1.0], format!("  {}", pi.gold()));
                             ui.same_line_with_spacing(0.0, 0.0);
                             ui.text_colored([0.85, 0.7, 0.0, 1.0], "g");
                             ui.same_line();
                             // Silver (light gray)
-                            ui.text_colored([0.78, 0.78, 0.82, 1.0], &format!("{}", pi.silver()));
+                            ui.text_colored([0.78, 0.78, 0.82, 1.0], format!("{}", pi.silver()));
                             ui.same_line_with_spacing(0.0, 0.0);
                             ui.text_colored([0.6, 0.6, 0.65, 1.0], "s");
                             ui.same_line();
                             // Copper (brown/orange)
-                            ui.text_colored([0.8, 0.5, 0.2, 1.0], &format!("{}", pi.copper_rem()));
+                            ui.text_colored([0.8, 0.5, 0.2, 1.0], format!("{}", pi.copper_rem()));
                             ui.same_line_with_spacing(0.0, 0.0);
                             ui.text_colored([0.65, 0.4, 0.15, 1.0], "c");
                         }
@@ -728,80 +1161,210 @@ impl ApplicationHandler for App {
                                     ui.input_text("Process Name", &mut state.config.process_name)
                                         .build();
 
-                                    ui.disabled(is_attached, || {
+                                    {
                                         if ui.button("Attach") {
-                                            let mut sys = System::new();
-                                            sys.refresh_processes(
-                                                sysinfo::ProcessesToUpdate::All,
-                                                true,
+                                            state.watchdog_suspended = false;
+                                            state.refresh_game_clients();
+                                            let selected = clients::auto_select(
+                                                &state.game_clients,
+                                                &state.config.last_character,
                                             );
-                                            let found = sys.processes().values().find(|p| {
-                                                p.name().to_string_lossy()
-                                                    == state.config.process_name.as_str()
-                                            });
-                                            match found {
-                                                Some(process) => {
-                                                    let pid = process.pid().as_u32();
-                                                    info!(
-                                                        "Found process '{}' with PID={}",
-                                                        state.config.process_name, pid
-                                                    );
-                                                    match state.reader.attach(pid) {
-                                                        Ok(()) => {
-                                                            state.attached_pid = Some(pid);
-                                                            state.chat_reader.reset();
-                                                            state.chat_messages.clear();
-                                                            state.status_text = format!(
-                                                                "Attached to {} (PID: {})",
-                                                                state.config.process_name, pid
-                                                            );
-                                                            state.config.save();
-                                                            info!(
-                                                                "Successfully attached to PID={}",
-                                                                pid
-                                                            );
-                                                        }
-                                                        Err(e) => {
-                                                            error!(
-                                                                "Failed to attach to PID={}: {}",
-                                                                pid, e
-                                                            );
-                                                            state.status_text =
-                                                                format!("Failed to attach: {}", e);
-                                                        }
-                                                    }
-                                                }
-                                                None => {
-                                                    warn!(
-                                                        "Process '{}' not found",
-                                                        state.config.process_name
-                                                    );
-                                                    state.status_text = format!(
-                                                        "Process '{}' not found",
-                                                        state.config.process_name
-                                                    );
-                                                }
+                                            if let Some(idx) = selected {
+                                                let pid = state.game_clients[idx].pid;
+                                                info!(
+                                                    "Found process '{}' with PID={}",
+                                                    state.config.process_name, pid
+                                                );
+                                                state.attach_client(idx);
+                                            } else if state.game_clients.is_empty() {
+                                                warn!(
+                                                    "Process '{}' not found",
+                                                    state.config.process_name
+                                                );
+                                                state.status_text = format!(
+                                                    "Process '{}' not found",
+                                                    state.config.process_name
+                                                );
+                                            } else {
+                                                state.status_text = format!(
+                                                    "{} clients running, pick one in the Process menu",
+                                                    state.game_clients.len()
+                                                );
                                             }
                                         }
-                                    });
+                                    }
 
                                     ui.same_line();
 
                                     ui.disabled(!is_attached, || {
-                                        if ui.button("Detach") {
+                                        if ui.button("Detach All") {
                                             info!("User requested detach");
-                                            if let Err(e) = state.reader.detach() {
-                                                error!("Detach error: {}", e);
-                                                state.status_text =
-                                                    format!("Detach error: {}", e);
-                                            } else {
-                                                state.attached_pid = None;
-                                                state.chat_reader.reset();
-                                                state.status_text = String::from("Detached");
-                                                info!("Detached successfully");
+                                            // Don't let the watchdog undo a manual detach.
+                                            state.watchdog_suspended = true;
+                                            state.lost_characters.clear();
+                                            while !state.sessions.is_empty() {
+                                                state.remove_session(0);
                                             }
+                                            state.status_text = String::from("Detached");
+                                            info!("Detached successfully");
+                                        }
+                                    });
+
+                                    if let Some(ref diagnosis) = state.attach_diagnosis {
+                                        ui.separator();
+                                        ui.text_colored([1.0, 0.5, 0.4, 1.0], "Cannot read game memory");
+                                        ui.text_wrapped(&diagnosis.problem);
+                                        ui.text_wrapped(&diagnosis.remedy);
+                                        if let Some(ref command) = diagnosis.command {
+                                            ui.text_colored([0.6, 0.9, 0.6, 1.0], command);
+                                            ui.same_line();
+                                            if ui.small_button("Copy##diagnosis") {
+                                                if let Some(ref mut cb) = state.clipboard {
+                                                    cb.copy(command);
+                                                }
+                                            }
+                                        }
+                                        ui.separator();
+                                    }
+
+                                    // ── Attached clients ─────────────────
+                                    // The selected one feeds the status bar and debug tools.
+                                    let mut detach = None;
+                                    for (idx, session) in state.sessions.iter().enumerate() {
+                                        if ui.small_button(format!("Detach##session{}", idx)) {
+                                            detach = Some(idx);
+                                        }
+                                        ui.same_line();
+                                        let label = format!(
+                                            "{}  (PID {}){}##session{}",
+                                            session.label(),
+                                            session.pid,
+                                            if session.recording { "  [REC]" } else { "" },
+                                            idx,
+                                        );
+                                        if ui
+                                            .selectable_config(&label)
+                                            .selected(idx == state.current_session)
+                                            .build()
+                                        {
+                                            state.current_session = idx;
+                                        }
+                                    }
+                                    if let Some(idx) = detach {
+                                        info!("User requested detach of PID={}", state.sessions[idx].pid);
+                                        state.watchdog_suspended = true;
+                                        state.remove_session(idx);
+                                        state.status_text = String::from("Detached");
+                                    }
+
+                                    // ── Running clients ──────────────────
+                                    ui.separator();
+                                    if ui.button("Refresh Clients") {
+                                        state.refresh_game_clients();
+                                    }
+                                    let mut picked = None;
+                                    ui.columns(2, "game_clients", false);
+                                    for (idx, client) in state.game_clients.iter().enumerate() {
+                                        let attached = state.sessions.iter().any(|s| s.pid == client.pid);
+                                        if ui
+                                            .selectable_config(format!("{}##client{}", client.label(), idx))
+                                            .selected(attached)
+                                            .disabled(attached)
+                                            .span_all_columns(true)
+                                            .build()
+                                        {
+                                            picked = Some(idx);
+                                        }
+                                        let hovered = ui.is_item_hovered();
+                                        ui.next_column();
+                                        ui.text_disabled(truncate_chars(
+                                            &client.command_line,
+                                            MAX_COMMAND_LINE_CHARS,
+                                        ));
+                                        ui.next_column();
+                                        if hovered {
+                                            ui.tooltip(|| {
+                                                ui.text(&client.command_line);
+                                                if let Some(ref prefix) = client.wine_prefix {
+                                                    ui.text(format!("Wine prefix: {}", prefix.display()));
+                                                }
+                                                if let Some(ref dir) = client.game_dir {
+                                                    ui.text(format!("Game folder: {}", dir.display()));
+                                                }
+                                            });
+                                        }
+                                    }
+                                    ui.columns(1, "game_clients", false);
+                                    if let Some(idx) = picked {
+                                        state.watchdog_suspended = false;
+                                        state.attach_client(idx);
+                                    }
+                                    ui.separator();
+
+                                    if ui.checkbox(
+                                        "Auto-reattach when the game restarts",
+                                        &mut state.config.auto_reattach,
+                                    ) {
+                                        state.config.save();
+                                    }
+
+                                    // Poll interval
+                                    let mut interval = state.config.poll_interval_ms as i32;
+                                    if ui
+                                        .input_int("Poll Interval (ms)", &mut interval)
+                                        .step(50)
+                                        .step_fast(250)
+                                        .build()
+                                    {
+                                        state.config.poll_interval_ms =
+                                            interval.clamp(20, 2000) as u64;
+                                        for session in &state.sessions {
+                                            session.polling.set_interval(state.config.poll_interval_ms);
+                                        }
+                                        state.config.save();
+                                    }
+
+                                    // ── Offset profile ───────────────────
+                                    ui.separator();
+                                    let mut names = vec!["Auto-detect".to_string()];
+                                    names.extend(state.profiles.iter().map(|p| p.name.clone()));
+                                    let mut profile_idx = state
+                                        .profiles
+                                        .iter()
+                                        .position(|p| p.name == state.config.offset_profile)
+                                        .map_or(0, |i| i + 1);
+                                    ui.disabled(is_attached, || {
+                                        if ui.combo_simple_string(
+                                            "Offset Profile",
+                                            &mut profile_idx,
+                                            &names,
+                                        ) {
+                                            state.config.offset_profile = if profile_idx == 0 {
+                                                String::new()
+                                            } else {
+                                                names[profile_idx].clone()
+                                            };
+                                            state.config.save();
+                                        }
+                                        ui.same_line();
+                                        if ui.button("Reload") {
+                                            state.profiles = profiles::load();
                                         }
                                     });
+
+                                    let session = state.current();
+                                    let active = session
+                                        .and_then(|s| s.active_profile)
+                                        .and_then(|i| state.profiles.get(i));
+                                    if let (Some(session), Some(active)) = (session, active) {
+                                        let client = session
+                                            .client_version
+                                            .map_or("unknown".to_string(), |v| v.to_string());
+                                        ui.text_colored(
+                                            [0.6, 0.6, 0.6, 1.0],
+                                            format!("Active profile: {} (client {})", active.name, client),
+                                        );
+                                    }
                                 }
                                 AppBarDropdown::Settings => {
                                     // ── Appearance ───────────────────────
@@ -832,12 +1395,10 @@ impl ApplicationHandler for App {
                                         .position(|f| f.name == state.config.font_name)
                                         .unwrap_or(0);
                                     if ui.combo_simple_string("Font", &mut font_idx, &font_labels)
+                                        && font_idx < state.available_fonts.len()
                                     {
-
-                                        if font_idx < state.available_fonts.len() {
-                                            state.config.font_name =
-                                                state.available_fonts[font_idx].name.clone();
-                                        }
+                                        state.config.font_name =
+                                            state.available_fonts[font_idx].name.clone();
                                     }
 
                                     // Font size
@@ -1020,12 +1581,10 @@ impl ApplicationHandler for App {
                                             "Target Language",
                                             &mut lang_idx,
                                             &lang_items,
-                                        ) {
-    
-                                            if lang_idx < state.target_languages.len() {
-                                                state.config.target_language =
-                                                    state.target_languages[lang_idx].0.clone();
-                                            }
+                                        ) && lang_idx < state.target_languages.len()
+                                        {
+                                            state.config.target_language =
+                                                state.target_languages[lang_idx].0.clone();
                                         }
                                     } else {
                                         ui.input_text(
@@ -1101,58 +1660,217 @@ impl ApplicationHandler for App {
 
                                     ui.text_wrapped(
                                         "Debug Scan reads all 60 chat buffer slots and \
-                                         logs their raw fields.",
+                                         logs their raw fields. The Memory Inspector \
+                                         shows memory as hex and decodes one chat slot live.",
                                     );
                                     ui.disabled(!is_attached, || {
                                         if ui.button("Run Debug Scan") {
                                             info!("User requested debug scan");
-                                            chat::debug_scan(&*state.reader);
+                                            if let Some(session) = state.current() {
+                                                chat::debug_scan(
+                                                    &**polling::lock_reader(&session.reader),
+                                                    &session.offsets,
+                                                );
+                                            }
                                             state.status_text =
                                                 "Debug scan complete (see log)".into();
                                         }
                                     });
+                                    ui.same_line();
+                                    if ui.button("Memory Inspector") {
+                                        state.inspector_window_open = true;
+                                        if state.hex_address_input.is_empty() {
+                                            if let Some(session) = state.current() {
+                                                state.hex_address_input =
+                                                    format!("{:08X}", session.offsets.chat_buffer_start);
+                                            }
+                                        }
+                                        state.refresh_inspector();
+                                    }
 
                                     ui.spacing();
                                     ui.separator();
 
                                     ui.text_wrapped(
-                                        "Memory Scanner searches the entire process \
-                                         address space for a given text string.",
+                                        "Memory Scanner finds strings and values in the \
+                                         game's memory and narrows them down with rescans.",
+                                    );
+                                    if ui.button("Memory Scanner") {
+                                        state.scanner_window_open = true;
+                                    }
+                                    ui.same_line();
+                                    if ui.button("Offset Discovery Wizard") {
+                                        state.discovery_window_open = true;
+                                    }
+
+                                    ui.spacing();
+                                    ui.separator();
+
+                                    ui.text_wrapped(
+                                        "Snapshots save the chat buffer, player info and \
+                                         object manager chain to a file that can be \
+                                         replayed without the game running.",
                                     );
                                     ui.disabled(!is_attached, || {
-                                        ui.input_text("Search Text", &mut state.search_text)
-                                            .build();
-                                        if ui.button("Scan Memory")
-                                            && !state.search_text.is_empty()
-                                        {
-                                            info!(
-                                                "Scanning memory for: \"{}\"",
-                                                state.search_text
+                                        let session = state.sessions.get(state.current_session);
+                                        if let (true, Some(session)) = (ui.button("Save Snapshot"), session) {
+                                            let pid = session.pid;
+                                            let file_name = format!(
+                                                "snapshot-{}.{}",
+                                                chrono::Local::now().format("%Y%m%d-%H%M%S"),
+                                                snapshot::SNAPSHOT_EXTENSION,
                                             );
-                                            match state
-                                                .reader
-                                                .scan_for_bytes(state.search_text.as_bytes())
-                                            {
-                                                Ok(addrs) => {
-                                                    if addrs.is_empty() {
-                                                        state.status_text =
-                                                            "Scan: no matches found".into();
-                                                        warn!(
-                                                            "No matches for \"{}\"",
-                                                            state.search_text
+                                            let dialog = rfd::FileDialog::new()
+                                                .set_directory(config::config_dir())
+                                                .set_file_name(&file_name)
+                                                .add_filter(
+                                                    "Snapshot",
+                                                    &[snapshot::SNAPSHOT_EXTENSION],
+                                                );
+                                            if let Some(path) = dialog.save_file() {
+                                                let snap = snapshot::SnapshotMemoryReader::capture(
+                                                    &**polling::lock_reader(&session.reader),
+                                                    pid,
+                                                    &session.offsets,
+                                                );
+                                                match snap.save(&path) {
+                                                    Ok(()) => {
+                                                        state.status_text = format!(
+                                                            "Snapshot saved ({} KB)",
+                                                            snap.total_bytes() / 1024
                                                         );
-                                                    } else {
+                                                    }
+                                                    Err(e) => {
+                                                        error!("Failed to save snapshot: {}", e);
+                                                        state.status_text =
+                                                            format!("Snapshot error: {}", e);
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    });
+                                    ui.same_line();
+                                    ui.disabled(is_attached, || {
+                                        if ui.button("Load Snapshot") {
+                                            let dialog = rfd::FileDialog::new()
+                                                .set_directory(config::config_dir())
+                                                .add_filter(
+                                                    "Snapshot",
+                                                    &[snapshot::SNAPSHOT_EXTENSION],
+                                                );
+                                            if let Some(path) = dialog.pick_file() {
+                                                match snapshot::SnapshotMemoryReader::load(&path) {
+                                                    Ok(snap) => {
+                                                        let pid = snap.pid();
+                                                        let offsets = snap.offsets();
+                                                        state.start_replay_session(pid, Box::new(snap), offsets);
                                                         state.status_text = format!(
-                                                            "Scan: {} matches (see log)",
-                                                            addrs.len()
+                                                            "Replaying snapshot (PID: {})",
+                                                            pid
                                                         );
-                                                        chat::analyze_found_addresses(&addrs);
+                                                    }
+                                                    Err(e) => {
+                                                        error!("Failed to load snapshot: {}", e);
+                                                        state.status_text =
+                                                            format!("Snapshot error: {}", e);
                                                     }
                                                 }
-                                                Err(e) => {
-                                                    state.status_text =
-                                                        format!("Scan error: {}", e);
-                                                    error!("Scan error: {}", e);
+                                            }
+                                        }
+                                    });
+
+                                    ui.spacing();
+                                    ui.separator();
+
+                                    ui.text_wrapped(
+                                        "Recording saves every chat buffer poll with its \
+                                         timing; a recording can be replayed later at \
+                                         original or accelerated speed.",
+                                    );
+                                    let is_recording = state.current().is_some_and(|s| s.recording);
+                                    ui.disabled(!is_attached || is_recording, || {
+                                        let session = state.sessions.get_mut(state.current_session);
+                                        if let (true, Some(session)) = (ui.button("Start Recording"), session) {
+                                            let pid = session.pid;
+                                            let file_name = format!(
+                                                "recording-{}.{}",
+                                                chrono::Local::now().format("%Y%m%d-%H%M%S"),
+                                                recording::RECORDING_EXTENSION,
+                                            );
+                                            let dialog = rfd::FileDialog::new()
+                                                .set_directory(config::config_dir())
+                                                .set_file_name(&file_name)
+                                                .add_filter(
+                                                    "Recording",
+                                                    &[recording::RECORDING_EXTENSION],
+                                                );
+                                            if let Some(path) = dialog.save_file() {
+                                                match recording::PollRecorder::create(
+                                                    &path,
+                                                    pid,
+                                                    &session.offsets,
+                                                ) {
+                                                    Ok(recorder) => {
+                                                        session.polling.start_recording(recorder);
+                                                        session.recording = true;
+                                                        state.status_text = "Recording...".into();
+                                                    }
+                                                    Err(e) => {
+                                                        error!("Failed to start recording: {}", e);
+                                                        state.status_text =
+                                                            format!("Recording error: {}", e);
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    });
+                                    ui.same_line();
+                                    ui.disabled(!is_recording, || {
+                                        let session = state.sessions.get_mut(state.current_session);
+                                        if let (true, Some(session)) = (ui.button("Stop Recording"), session) {
+                                            session.polling.stop_recording();
+                                            session.recording = false;
+                                            state.status_text = "Recording saved".into();
+                                        }
+                                    });
+
+                                    ui.disabled(is_attached, || {
+                                        let speed_labels: Vec<&str> =
+                                            REPLAY_SPEEDS.iter().map(|(label, _)| *label).collect();
+                                        ui.set_next_item_width(80.0);
+                                        ui.combo_simple_string(
+                                            "Speed",
+                                            &mut state.replay_speed_index,
+                                            &speed_labels,
+                                        );
+                                        ui.same_line();
+                                        if ui.button("Replay Recording") {
+                                            let dialog = rfd::FileDialog::new()
+                                                .set_directory(config::config_dir())
+                                                .add_filter(
+                                                    "Recording",
+                                                    &[recording::RECORDING_EXTENSION],
+                                                );
+                                            if let Some(path) = dialog.pick_file() {
+                                                let (label, speed) =
+                                                    REPLAY_SPEEDS[state.replay_speed_index];
+                                                match recording::ReplayMemoryReader::load(
+                                                    &path, speed,
+                                                ) {
+                                                    Ok(replay) => {
+                                                        let pid = replay.pid();
+                                                        let offsets = replay.offsets();
+                                                        state.start_replay_session(pid, Box::new(replay), offsets);
+                                                        state.status_text = format!(
+                                                            "Replaying recording at {} (PID: {})",
+                                                            label, pid
+                                                        );
+                                                    }
+                                                    Err(e) => {
+                                                        error!("Failed to load recording: {}", e);
+                                                        state.status_text =
+                                                            format!("Replay error: {}", e);
+                                                    }
                                                 }
                                             }
                                         }
@@ -1164,7 +1882,7 @@ impl ApplicationHandler for App {
 
                 // ── Close dropdown on outside click ──────────────
                 let mouse = ui.io().mouse_pos;
-                let mouse_in_dropdown = dropdown_rect.map_or(false, |(pos, size)| {
+                let mouse_in_dropdown = dropdown_rect.is_some_and(|(pos, size)| {
                     mouse[0] >= pos[0]
                         && mouse[0] <= pos[0] + size[0]
                         && mouse[1] >= pos[1]
@@ -1184,7 +1902,7 @@ impl ApplicationHandler for App {
                         .size([500.0, 620.0], imgui::Condition::FirstUseEver)
                         .opened(&mut still_open)
                         .build(|| {
-                            ui.text(&format!(
+                            ui.text(format!(
                                 "{} entries in glossary",
                                 state.glossary.entries.len()
                             ));
@@ -1529,7 +2247,7 @@ impl ApplicationHandler for App {
                             if !state.translator_error.is_empty() {
                                 ui.text_colored(
                                     [1.0, 0.3, 0.3, 1.0],
-                                    &format!("Error: {}", state.translator_error),
+                                    format!("Error: {}", state.translator_error),
                                 );
                             }
 
@@ -1546,7 +2264,522 @@ impl ApplicationHandler for App {
                     }
                 }
 
+                // ── Window: Memory Scanner ───────────────────────
+                if let Some((scanner, status)) =
+                    state.scanner_running.as_ref().and_then(|scan| scan.finished())
+                {
+                    state.scanner = scanner;
+                    state.scanner_status = status;
+                    state.scanner_running = None;
+                }
+                if state.scanner_window_open {
+                    let mut still_open = true;
+                    ui.window("Memory Scanner")
+                        .size([560.0, 520.0], imgui::Condition::FirstUseEver)
+                        .opened(&mut still_open)
+                        .build(|| {
+                            let types: Vec<&str> =
+                                scanner::ValueType::ALL.iter().map(|t| t.label()).collect();
+                            let value_type = scanner::ValueType::ALL[state.scanner_type_index];
+                            let filters: Vec<scanner::Filter> = scanner::Filter::ALL
+                                .iter()
+                                .copied()
+                                .filter(|f| f.applies_to(state.scanner.value_type))
+                                .collect();
+                            state.scanner_filter_index =
+                                state.scanner_filter_index.min(filters.len() - 1);
+                            let filter = filters[state.scanner_filter_index];
+                            let has_hits = state.scanner.scan_count > 0;
+                            let running = state.scanner_running.is_some();
+
+                            ui.disabled(has_hits || running, || {
+                                ui.set_next_item_width(140.0);
+                                ui.combo_simple_string(
+                                    "Type",
+                                    &mut state.scanner_type_index,
+                                    &types,
+                                );
+                            });
+                            ui.same_line();
+                            ui.set_next_item_width(200.0);
+                            ui.input_text("Value", &mut state.scanner_input).build();
+
+                            ui.disabled(!is_attached || running, || {
+                                let session = state.sessions.get(state.current_session);
+                                if let (true, Some(session)) = (ui.button("First Scan"), session) {
+                                    let reader = session.reader.clone();
+                                    let input = state.scanner_input.clone();
+                                    state.start_scan(move |scan| {
+                                        match scan.first_scan(&reader, value_type, &input) {
+                                            Ok(n) if n >= scanner::MAX_HITS => {
+                                                format!("{} hits (capped), narrow with Next Scan", n)
+                                            }
+                                            Ok(n) => format!("{} hits", n),
+                                            Err(e) => e,
+                                        }
+                                    });
+                                    state.scanner_filter_index = 0;
+                                }
+                                ui.same_line();
+                                ui.disabled(!has_hits, || {
+                                    let labels: Vec<&str> =
+                                        filters.iter().map(|f| f.label()).collect();
+                                    ui.set_next_item_width(120.0);
+                                    ui.combo_simple_string(
+                                        "##scan_filter",
+                                        &mut state.scanner_filter_index,
+                                        &labels,
+                                    );
+                                    ui.same_line();
+                                    let session = state.sessions.get(state.current_session);
+                                    if let (true, Some(session)) = (ui.button("Next Scan"), session) {
+                                        let reader = session.reader.clone();
+                                        let input = state.scanner_input.clone();
+                                        state.start_scan(move |scan| {
+                                            match scan.next_scan(&reader, filter, &input) {
+                                                Ok(n) => format!(
+                                                    "{} hits after scan {}",
+                                                    n, scan.scan_count
+                                                ),
+                                                Err(e) => e,
+                                            }
+                                        });
+                                    }
+                                    ui.same_line();
+                                    let session = state.sessions.get(state.current_session);
+                                    if let (true, Some(session)) = (ui.button("Refresh"), session) {
+                                        let reader = session.reader.clone();
+                                        state.start_scan(move |scan| {
+                                            match scan.refresh(&reader) {
+                                                Ok(()) => {
+                                                    format!("{} hits refreshed", scan.hits.len())
+                                                }
+                                                Err(e) => format!("Read error: {}", e),
+                                            }
+                                        });
+                                    }
+                                    ui.same_line();
+                                    if ui.button("New Scan") {
+                                        state.scanner.reset();
+                                        state.scanner_status.clear();
+                                    }
+                                });
+                            });
+                            if !is_attached {
+                                ui.text_colored([0.6, 0.6, 0.6, 1.0], "Attach to the game first.");
+                            }
+                            if !state.scanner_status.is_empty() {
+                                ui.text(&state.scanner_status);
+                            }
+
+                            // ── Hits ─────────────────────────────
+                            let value_type = state.scanner.value_type;
+                            ui.columns(4, "scanner_hits_header", false);
+                            for header in ["Address", "Value", "Previous", ""] {
+                                ui.text_disabled(header);
+                                ui.next_column();
+                            }
+                            ui.columns(1, "scanner_hits_header", false);
+                            if let Some(_child) = ui
+                                .child_window("scanner_hits")
+                                .size([0.0, 240.0])
+                                .border(true)
+                                .begin()
+                            {
+                                ui.columns(4, "scanner_hits", false);
+                                let clipper =
+                                    imgui::ListClipper::new(state.scanner.hits.len() as i32)
+                                        .begin(ui);
+                                for row in clipper.iter() {
+                                    let hit = &state.scanner.hits[row as usize];
+                                    ui.text(format!("0x{:08X}", hit.address));
+                                    ui.next_column();
+                                    ui.text(value_type.format(&hit.value));
+                                    ui.next_column();
+                                    if !hit.previous.is_empty() {
+                                        ui.text_disabled(value_type.format(&hit.previous));
+                                    }
+                                    ui.next_column();
+                                    let marked = state
+                                        .scanner_candidates
+                                        .iter()
+                                        .any(|&(address, _)| address == hit.address);
+                                    ui.disabled(marked, || {
+                                        if ui.small_button(format!("Mark##hit{}", row)) {
+                                            state.scanner_candidates.push((hit.address, 0));
+                                        }
+                                    });
+                                    ui.next_column();
+                                }
+                                ui.columns(1, "scanner_hits", false);
+                            }
+
+                            // ── Candidate offsets ────────────────
+                            ui.separator();
+                            ui.text("Candidate offsets");
+                            let mut remove = None;
+                            let mut save = None;
+                            let profile_idx = state
+                                .current()
+                                .and_then(|s| s.active_profile)
+                                .filter(|&p| p < state.profiles.len());
+                            let profile_name = profile_idx.map(|p| state.profiles[p].name.clone());
+                            for (i, (address, field_idx)) in
+                                state.scanner_candidates.iter_mut().enumerate()
+                            {
+                                let address = *address;
+                                if ui.small_button(format!("x##candidate{}", i)) {
+                                    remove = Some(i);
+                                }
+                                ui.same_line();
+                                ui.text(format!("0x{:08X}", address));
+                                ui.same_line();
+                                ui.set_next_item_width(160.0);
+                                ui.combo_simple_string(
+                                    format!("##candidate_field{}", i),
+                                    field_idx,
+                                    offsets::Offsets::ADDRESS_FIELDS,
+                                );
+                                ui.same_line();
+                                ui.disabled(profile_idx.is_none(), || {
+                                    let label = match &profile_name {
+                                        Some(name) => {
+                                            format!("Save to '{}'##candidate{}", name, i)
+                                        }
+                                        None => format!("Save to profile##candidate{}", i),
+                                    };
+                                    if let (true, Some(p)) = (ui.button(&label), profile_idx) {
+                                        save = Some((p, address, *field_idx));
+                                    }
+                                });
+                            }
+                            if let Some((p, address, field_idx)) = save {
+                                let field = offsets::Offsets::ADDRESS_FIELDS[field_idx];
+                                state.set_profile_address(p, field, address);
+                                state.scanner_status = format!(
+                                    "Saved {}=0x{:08X} to '{}'",
+                                    field, address, state.profiles[p].name
+                                );
+                            }
+                            if let Some(i) = remove {
+                                state.scanner_candidates.remove(i);
+                            }
+                            if state.scanner_candidates.is_empty() {
+                                ui.text_disabled("Mark a hit to save it as an offset.");
+                            }
+                        });
+                    if !still_open {
+                        state.scanner_window_open = false;
+                    }
+                }
+
+                // ── Window: Memory Inspector ─────────────────────
+                if state.inspector_window_open {
+                    if is_attached
+                        && state.inspector_live
+                        && state.inspector_last_read.elapsed() >= INSPECTOR_REFRESH
+                    {
+                        state.refresh_inspector();
+                    }
+                    let mut still_open = true;
+                    let mut refresh = false;
+                    ui.window("Memory Inspector")
+                        .size([720.0, 620.0], imgui::Condition::FirstUseEver)
+                        .opened(&mut still_open)
+                        .build(|| {
+                            if !is_attached {
+                                ui.text_colored([0.6, 0.6, 0.6, 1.0], "Attach to the game first.");
+                            }
+                            let chat_start = state.current().map(|s| s.offsets.chat_buffer_start);
+                            let slot_addr = state
+                                .current()
+                                .map(|s| chat::slot_address(&s.offsets, state.inspector_slot as usize));
+
+                            // ── Hex viewer ───────────────────────
+                            ui.set_next_item_width(140.0);
+                            refresh |= ui
+                                .input_text("Address", &mut state.hex_address_input)
+                                .chars_hexadecimal(true)
+                                .build();
+                            ui.same_line();
+                            ui.set_next_item_width(120.0);
+                            if ui
+                                .input_int("Length", &mut state.hex_length)
+                                .step(16)
+                                .step_fast(256)
+                                .build()
+                            {
+                                state.hex_length = state.hex_length.clamp(16, HEX_VIEW_MAX_LEN);
+                                refresh = true;
+                            }
+                            let page = state.hex_length as usize;
+                            let base = hexview::parse_address(&state.hex_address_input);
+                            ui.disabled(base.is_none(), || {
+                                if ui.button("<<") {
+                                    let address = base.unwrap_or(0).saturating_sub(page);
+                                    state.hex_address_input = format!("{:08X}", address);
+                                    refresh = true;
+                                }
+                                ui.same_line();
+                                if ui.button(">>") {
+                                    let address = base.unwrap_or(0).saturating_add(page);
+                                    state.hex_address_input = format!("{:08X}", address);
+                                    refresh = true;
+                                }
+                            });
+                            ui.same_line();
+                            if let (true, Some(address)) = (ui.button("Chat buffer"), chat_start) {
+                                state.hex_address_input = format!("{:08X}", address);
+                                refresh = true;
+                            }
+                            ui.same_line();
+                            if let (true, Some(address)) = (ui.button("Inspected slot"), slot_addr) {
+                                state.hex_address_input = format!("{:08X}", address);
+                                refresh = true;
+                            }
+                            ui.same_line();
+                            ui.checkbox("Live", &mut state.inspector_live);
+                            ui.same_line();
+                            refresh |= ui.button("Refresh");
+                            if !state.hex_error.is_empty() {
+                                ui.text_colored([1.0, 0.7, 0.3, 1.0], &state.hex_error);
+                            }
+
+                            if let Some(_child) = ui
+                                .child_window("hex_view")
+                                .size([0.0, 240.0])
+                                .border(true)
+                                .horizontal_scrollbar(true)
+                                .begin()
+                            {
+                                let rows = state.hex_length as usize / hexview::BYTES_PER_ROW;
+                                let clipper = imgui::ListClipper::new(rows as i32).begin(ui);
+                                for row in clipper.iter() {
+                                    let start = row as usize * hexview::BYTES_PER_ROW;
+                                    let end = (start + hexview::BYTES_PER_ROW).min(state.hex_bytes.len());
+                                    let bytes = state.hex_bytes.get(start..end).unwrap_or_default();
+                                    ui.text(hexview::format_row(state.hex_base + start, bytes));
+                                }
+                            }
+
+                            // ── Chat slot ────────────────────────
+                            ui.separator();
+                            ui.set_next_item_width(200.0);
+                            if ui.slider(
+                                "Chat slot",
+                                0,
+                                offsets::CHAT_BUFFER_SIZE as i32 - 1,
+                                &mut state.inspector_slot,
+                            ) {
+                                refresh = true;
+                            }
+                            if let Some(address) = slot_addr {
+                                ui.same_line();
+                                ui.text_disabled(format!("at 0x{:08X}", address));
+                            }
+                            if state.slot_bytes.len() < offsets::CHAT_MESSAGE_STRIDE {
+                                ui.text_colored(
+                                    [1.0, 0.7, 0.3, 1.0],
+                                    format!(
+                                        "Only {} of {} bytes readable",
+                                        state.slot_bytes.len(),
+                                        offsets::CHAT_MESSAGE_STRIDE
+                                    ),
+                                );
+                            }
+
+                            ui.columns(4, "slot_fields", true);
+                            for header in ["Field", "Offset", "Value", "Raw bytes"] {
+                                ui.text_disabled(header);
+                                ui.next_column();
+                            }
+                            ui.separator();
+                            for field in chat::decode_slot(&state.slot_bytes) {
+                                ui.text(field.name);
+                                ui.next_column();
+                                ui.text(format!("+0x{:04X}", field.offset));
+                                ui.next_column();
+                                ui.text(&field.value);
+                                if ui.is_item_hovered() {
+                                    ui.tooltip(|| {
+                                        let _wrap = ui.push_text_wrap_pos_with_pos(500.0);
+                                        ui.text(&field.value);
+                                    });
+                                }
+                                ui.next_column();
+                                ui.text(hexview::hex_bytes(&field.raw));
+                                ui.next_column();
+                            }
+                            ui.columns(1, "slot_fields", false);
+
+                            // ── Profile pointers ─────────────────
+                            ui.separator();
+                            ui.text("Profile pointers");
+                            ui.same_line();
+                            ui.text_disabled("([[profile.pointer]] in offsets.toml)");
+                            if state.pointer_values.is_empty() {
+                                ui.text_disabled("The active profile declares no pointers.");
+                            }
+                            ui.columns(3, "pointer_values", true);
+                            for (name, path, value) in &state.pointer_values {
+                                ui.text(name);
+                                ui.next_column();
+                                ui.text_disabled(path);
+                                ui.next_column();
+                                match value {
+                                    Ok(value) => ui.text(value),
+                                    Err(e) => ui.text_colored([1.0, 0.4, 0.4, 1.0], e),
+                                }
+                                ui.next_column();
+                            }
+                            ui.columns(1, "pointer_values", false);
+                        });
+                    if refresh && is_attached {
+                        state.refresh_inspector();
+                    }
+                    if !still_open {
+                        state.inspector_window_open = false;
+                    }
+                }
+
+                // ── Window: Offset Discovery ─────────────────────
+                if state.discovery_window_open {
+                    let mut still_open = true;
+                    ui.window("Offset Discovery")
+                        .size([520.0, 420.0], imgui::Condition::FirstUseEver)
+                        .opened(&mut still_open)
+                        .build(|| {
+                            ui.text_wrapped(
+                                "1. Say a unique phrase in game chat (e.g. /say xq7 wizard).",
+                            );
+                            ui.text_wrapped(
+                                "2. Enter the same phrase below and scan. Each match is \
+                                 checked by parsing the neighbouring 0x17C0-byte slots.",
+                            );
+                            ui.text_wrapped("3. Save the best candidate to the offset profile.");
+                            ui.separator();
+
+                            if let Some((candidates, status)) =
+                                state.discovery_running.as_ref().and_then(|scan| scan.finished())
+                            {
+                                state.discovery_candidates = candidates;
+                                state.discovery_selected = 0;
+                                state.discovery_status = status;
+                                state.discovery_running = None;
+                            }
+                            let running = state.discovery_running.is_some();
+                            ui.disabled(!is_attached || running, || {
+                                ui.input_text("Phrase", &mut state.discovery_phrase).build();
+                                ui.same_line();
+                                let session = state.sessions.get(state.current_session);
+                                if let (true, Some(session)) =
+                                    (ui.button("Scan##discovery") && !state.discovery_phrase.is_empty(), session)
+                                {
+                                    info!(
+                                        "Offset discovery: scanning for \"{}\"",
+                                        state.discovery_phrase
+                                    );
+                                    let reader = session.reader.clone();
+                                    let phrase = state.discovery_phrase.clone();
+                                    let scan = move |candidates: &mut Vec<chat::BufferCandidate>| {
+                                        let addrs = match polling::scan_shared(
+                                            &reader,
+                                            phrase.as_bytes(),
+                                            memory::SCAN_MAX_RESULTS,
+                                            |_| true,
+                                        ) {
+                                            Ok(addrs) => addrs,
+                                            Err(e) => {
+                                                error!("Offset discovery scan error: {}", e);
+                                                return format!("Scan error: {}", e);
+                                            }
+                                        };
+                                        chat::analyze_found_addresses(&addrs);
+                                        *candidates = chat::find_buffer_candidates(
+                                            &**polling::lock_reader(&reader),
+                                            &addrs,
+                                            &phrase,
+                                        );
+                                        format!(
+                                            "{} matches, {} candidates",
+                                            addrs.len(),
+                                            candidates.len()
+                                        )
+                                    };
+                                    state.discovery_running =
+                                        Some(scanner::RunningScan::start(Vec::new(), scan));
+                                    state.discovery_candidates.clear();
+                                    state.discovery_status = "Scanning...".into();
+                                }
+                            });
+                            if !is_attached {
+                                ui.text_colored([0.6, 0.6, 0.6, 1.0], "Attach to the game first.");
+                            }
+                            if !state.discovery_status.is_empty() {
+                                ui.text(&state.discovery_status);
+                            }
+
+                            ui.separator();
+                            let current_start = state.current().map(|s| s.offsets.chat_buffer_start);
+                            for (i, c) in state.discovery_candidates.iter().enumerate() {
+                                let current = Some(c.buffer_start) == current_start;
+                                let label = format!(
+                                    "0x{:08X}  {}/{} valid slots  (slot {}, {}){}",
+                                    c.buffer_start,
+                                    c.valid_slots,
+                                    offsets::CHAT_BUFFER_SIZE,
+                                    c.slot,
+                                    c.field,
+                                    if current { "  [current]" } else { "" },
+                                );
+                                if ui
+                                    .selectable_config(&label)
+                                    .selected(state.discovery_selected == i)
+                                    .build()
+                                {
+                                    state.discovery_selected = i;
+                                }
+                            }
+
+                            let selected = state
+                                .discovery_candidates
+                                .get(state.discovery_selected)
+                                .map(|c| c.buffer_start);
+                            let profile_idx = state
+                                .current()
+                                .and_then(|s| s.active_profile)
+                                .filter(|&i| i < state.profiles.len());
+                            ui.disabled(selected.is_none() || profile_idx.is_none(), || {
+                                let label = match profile_idx {
+                                    Some(i) => format!("Save to profile '{}'", state.profiles[i].name),
+                                    None => "Save to profile".to_string(),
+                                };
+                                if ui.button(&label) {
+                                    if let (Some(start), Some(i)) = (selected, profile_idx) {
+                                        state.set_profile_address(i, "chat_buffer_start", start);
+                                        state.discovery_status = format!(
+                                            "Saved 0x{:08X} to '{}'",
+                                            start, state.profiles[i].name
+                                        );
+                                    }
+                                }
+                            });
+                            if profile_idx.is_some_and(|i| state.profiles[i].use_signatures) {
+                                ui.text_colored(
+                                    [0.6, 0.6, 0.6, 1.0],
+                                    "Saving pins chat_buffer_start in this profile so \
+                                     signatures keep the saved address on the next attach.",
+                                );
+                            }
+                        });
+                    if !still_open {
+                        state.discovery_window_open = false;
+                    }
+                }
+
                 // ── Window: Chat ─────────────────────────────────
+                let mut translate_requests: Vec<(u64, Vec<TextSegment>)> = Vec::new();
+                let mut sender_actions: Vec<SenderAction> = Vec::new();
                 ui.window("Chat")
                     .size([1080.0, 700.0], imgui::Condition::FirstUseEver)
                     .position([10.0, 45.0], imgui::Condition::FirstUseEver)
@@ -1563,6 +2796,14 @@ impl ApplicationHandler for App {
                                     .chat_messages
                                     .iter()
                                     .filter(|m| active_tab.matches(m.message_type))
+                                    .filter(|m| matches_source(m, &state.chat_source_filter))
+                                    .filter(|m| {
+                                        matches_sender(
+                                            m,
+                                            &state.chat_sender_filter,
+                                            &state.config.muted_senders,
+                                        )
+                                    })
                                     .map(|m| m.display_line())
                                     .collect::<Vec<_>>()
                                     .join("\n");
@@ -1586,23 +2827,68 @@ impl ApplicationHandler for App {
                             state.active_tab = 0;
                         }
 
+                        if !state.config.muted_senders.is_empty() {
+                            ui.same_line();
+                            let label =
+                                format!("Unmute All ({})", state.config.muted_senders.len());
+                            if ui.button(&label) {
+                                state.config.muted_senders.clear();
+                                state.config.save();
+                            }
+                            if ui.is_item_hovered() {
+                                ui.tooltip_text(state.config.muted_senders.join(", "));
+                            }
+                        }
+
                         ui.same_line();
                         if ui.checkbox("Translate Always", &mut state.auto_translate) {
                             state.config.auto_translate = state.auto_translate;
                             state.config.save();
                         }
 
+                        // Client filter, only useful with several clients attached
+                        let show_source = state.sessions.len() > 1;
+                        if show_source {
+                            ui.same_line();
+                            let mut sources = vec!["All clients".to_string()];
+                            sources.extend(state.sessions.iter().map(|s| s.label()));
+                            let mut source_idx = sources
+                                .iter()
+                                .position(|s| *s == state.chat_source_filter)
+                                .unwrap_or(0);
+                            ui.set_next_item_width(140.0);
+                            if ui.combo_simple_string("##chat_source", &mut source_idx, &sources) {
+                                state.chat_source_filter = if source_idx == 0 {
+                                    String::new()
+                                } else {
+                                    sources[source_idx].clone()
+                                };
+                            }
+                        } else {
+                            state.chat_source_filter.clear();
+                        }
+
+                        if !state.chat_sender_filter.is_empty() {
+                            ui.text_colored(
+                                [0.6, 0.8, 1.0, 1.0],
+                                format!("Showing messages from {}", state.chat_sender_filter),
+                            );
+                            ui.same_line();
+                            if ui.small_button("Show Everyone") {
+                                state.chat_sender_filter.clear();
+                            }
+                        }
+
                         // Translation error warning bar
                         if !state.translation_error.is_empty() {
                             ui.text_colored(
                                 [1.0, 0.8, 0.2, 1.0],
-                                &format!("Translation: {}", state.translation_error),
+                                format!("Translation: {}", state.translation_error),
                             );
                         }
 
                         ui.separator();
 
-                        let mut translate_requests: Vec<(u64, Vec<TextSegment>)> = Vec::new();
                         if let Some(_tab_bar) = ui.tab_bar("chat_tabs") {
                             for (tab_idx, tab) in state.chat_tabs.iter().enumerate() {
                                 if let Some(_tab_item) = ui.tab_item(&tab.name) {
@@ -1619,30 +2905,119 @@ impl ApplicationHandler for App {
                                         &mut translate_requests,
                                         &state.glossary,
                                         &state.config.app_language,
+                                        &state.chat_source_filter,
+                                        show_source,
+                                        &state.unit_details,
+                                        &state.chat_sender_filter,
+                                        &state.config.muted_senders,
+                                        &mut sender_actions,
                                     );
                                 }
                             }
                         }
-                        // Process any translation requests from [T] button clicks
-                        if let Some(ref service) = state.translation_service {
-                            for (msg_id, segments) in translate_requests {
-                                let (text, link_names) =
-                                    translation::prepare_for_translation(&segments);
-                                if !text.trim().is_empty() {
-                                    state
-                                        .translations
-                                        .insert(msg_id, TranslationEntry::Pending);
-                                    service.translate(TranslationRequest {
-                                        message_id: msg_id,
-                                        text,
-                                        link_names,
-                                        source_lang: None,
-                                        target_lang: None,
-                                    });
-                                }
+                    });
+
+                // ── Windows: whisper threads and per-sender views ─
+                let show_source = state.sessions.len() > 1;
+                for sender_window in state.sender_windows.iter_mut() {
+                    let name = &sender_window.name;
+                    let (title, tab) = if sender_window.whispers_only {
+                        (
+                            format!("Whispers: {}###whispers_{}", name, name),
+                            ChatTab {
+                                name: name.clone(),
+                                filter: Some(vec![
+                                    chat::ChatMessageType::Whisper,
+                                    chat::ChatMessageType::WhisperInform,
+                                ]),
+                            },
+                        )
+                    } else {
+                        (
+                            format!("Messages from {}###sender_{}", name, name),
+                            ChatTab {
+                                name: name.clone(),
+                                filter: None,
+                            },
+                        )
+                    };
+                    ui.window(&title)
+                        .size([600.0, 350.0], imgui::Condition::FirstUseEver)
+                        .opened(&mut sender_window.open)
+                        .build(|| {
+                            render_chat_area(
+                                ui,
+                                &state.chat_messages,
+                                &tab,
+                                0,
+                                state.had_new_messages,
+                                &mut state.clipboard,
+                                &state.translations,
+                                state.translation_service.is_some(),
+                                &mut translate_requests,
+                                &state.glossary,
+                                &state.config.app_language,
+                                "",
+                                show_source,
+                                &state.unit_details,
+                                &tab.name,
+                                &state.config.muted_senders,
+                                &mut sender_actions,
+                            );
+                        });
+                }
+                state.sender_windows.retain(|w| w.open);
+
+                for action in sender_actions {
+                    let (name, whispers_only) = match action {
+                        SenderAction::Filter(name) => {
+                            state.chat_sender_filter = name;
+                            continue;
+                        }
+                        SenderAction::Mute(name) => {
+                            if !state.config.muted_senders.contains(&name) {
+                                state.config.muted_senders.push(name);
+                                state.config.save();
                             }
+                            continue;
                         }
-                    });
+                        SenderAction::Unmute(name) => {
+                            state.config.muted_senders.retain(|n| *n != name);
+                            state.config.save();
+                            continue;
+                        }
+                        SenderAction::WhisperThread(name) => (name, true),
+                        SenderAction::ShowAll(name) => (name, false),
+                    };
+                    let already_open = state
+                        .sender_windows
+                        .iter()
+                        .any(|w| w.name == name && w.whispers_only == whispers_only);
+                    if !already_open {
+                        state.sender_windows.push(SenderWindow {
+                            name,
+                            whispers_only,
+                            open: true,
+                        });
+                    }
+                }
+
+                // Process any translation requests from [T] button clicks
+                if let Some(ref service) = state.translation_service {
+                    for (msg_id, segments) in translate_requests {
+                        let (text, link_names) = translation::prepare_for_translation(&segments);
+                        if !text.trim().is_empty() {
+                            state.translations.insert(msg_id, TranslationEntry::Pending);
+                            service.translate(TranslationRequest {
+                                message_id: msg_id,
+                                text,
+                                link_names,
+                                source_lang: None,
+                                target_lang: None,
+                            });
+                        }
+                    }
+                }
 
                 let draw_data = imgui.render();
 
@@ -1672,6 +3047,14 @@ impl ApplicationHandler for App {
 
 // ─── URL opener ──────────────────────────────────────────────────────
 
+/// `text` cut to `max` characters, with "..." when something was cut.
+fn truncate_chars(text: &str, max: usize) -> String {
+    match text.char_indices().nth(max) {
+        Some((end, _)) => format!("{}...", &text[..end]),
+        None => text.to_string(),
+    }
+}
+
 fn open_url(url: &str) {
     #[cfg(windows)]
     {
@@ -1779,6 +3162,133 @@ fn render_plain_with_glossary(
 
 // ─── Chat area renderer ─────────────────────────────────────────────
 
+/// Whether `msg` passes the chat client filter (empty = all clients).
+fn matches_source(msg: &ChatMessage, filter: &str) -> bool {
+    filter.is_empty() || msg.source == filter
+}
+
+/// Whether `msg` passes the sender filter. With no filter, muted players are hidden; a
+/// filter on a muted player still shows them.
+fn matches_sender(msg: &ChatMessage, filter: &str, muted: &[String]) -> bool {
+    if filter.is_empty() {
+        !muted.contains(&msg.sender_name)
+    } else {
+        msg.sender_name == filter
+    }
+}
+
+/// Something picked in the sender menu, applied once the chat windows are drawn.
+enum SenderAction {
+    Filter(String),
+    Mute(String),
+    Unmute(String),
+    WhisperThread(String),
+    ShowAll(String),
+}
+
+/// Popup with the actions for one player, opened by clicking their name.
+fn render_sender_menu(
+    ui: &imgui::Ui,
+    popup_id: &str,
+    name: &str,
+    muted: &[String],
+    clipboard: &mut Option<clipboard::ClipboardHelper>,
+    actions: &mut Vec<SenderAction>,
+) {
+    let Some(_popup) = ui.begin_popup(popup_id) else {
+        return;
+    };
+    ui.text_disabled(name);
+    ui.separator();
+    if ui.selectable("Filter This Sender") {
+        actions.push(SenderAction::Filter(name.to_string()));
+    }
+    if muted.iter().any(|n| n == name) {
+        if ui.selectable("Unmute Sender") {
+            actions.push(SenderAction::Unmute(name.to_string()));
+        }
+    } else if ui.selectable("Mute Sender") {
+        actions.push(SenderAction::Mute(name.to_string()));
+    }
+    if ui.selectable("Open Whisper Thread") {
+        actions.push(SenderAction::WhisperThread(name.to_string()));
+    }
+    if ui.selectable(format!("Copy /w {}", name)) {
+        if let Some(ref mut cb) = clipboard {
+            cb.copy(&format!("/w {} ", name));
+        }
+    }
+    if ui.selectable("Show All Messages From Sender") {
+        actions.push(SenderAction::ShowAll(name.to_string()));
+    }
+}
+
+/// Stable marker color per client so the same character keeps its color.
+fn source_color(source: &str) -> [f32; 4] {
+    const PALETTE: &[[f32; 4]] = &[
+        [0.4, 0.8, 1.0, 1.0],
+        [1.0, 0.6, 0.3, 1.0],
+        [0.6, 1.0, 0.5, 1.0],
+        [1.0, 0.5, 0.8, 1.0],
+        [0.9, 0.9, 0.4, 1.0],
+        [0.7, 0.6, 1.0, 1.0],
+    ];
+    let hash = source.bytes().fold(0usize, |h, b| h.wrapping_mul(31).wrapping_add(b as usize));
+    PALETTE[hash % PALETTE.len()]
+}
+
+/// Draw "[Type] Name: " with the name in its class color when the sender was seen in
+/// the Object Manager, and level/race/class on hover. Clicking a player's name opens
+/// `sender_popup`. Monster messages get an [NPC] badge linking to Wowhead. Returns
+/// whether the prefix is hovered.
+fn render_prefix(
+    ui: &imgui::Ui,
+    msg: &ChatMessage,
+    msg_color: [f32; 4],
+    units: &HashMap<u64, player::UnitDetails>,
+    sender_popup: &str,
+) -> bool {
+    let mut badge_hovered = false;
+    if let Some(entry) = msg.npc_entry() {
+        ui.text_colored([0.9, 0.75, 0.3, 1.0], "[NPC]");
+        if ui.is_item_hovered() {
+            badge_hovered = true;
+            let url = chat::WowLinkType::Npc(entry).wowhead_url(&msg.sender_name);
+            ui.tooltip_text(format!("Creature entry {}\n{}", entry, url));
+            if ui.is_mouse_clicked(imgui::MouseButton::Left) {
+                open_url(&url);
+            }
+        }
+        ui.same_line();
+    }
+
+    if msg.sender_name.is_empty() || msg.message_type.is_monster() {
+        ui.text_colored(msg_color, msg.display_prefix());
+        return badge_hovered || ui.is_item_hovered();
+    }
+    let details = units.get(&msg.sender_guid.raw());
+
+    ui.text_colored(msg_color, format!("[{}] ", msg.type_label()));
+    let mut hovered = badge_hovered || ui.is_item_hovered();
+    ui.same_line_with_spacing(0.0, 0.0);
+    let name_color = details.and_then(|d| d.class_color()).unwrap_or(msg_color);
+    ui.text_colored(name_color, &msg.sender_name);
+    if ui.is_item_hovered() {
+        hovered = true;
+        match details {
+            Some(details) => ui.tooltip_text(format!("{}\nClick for options", details.summary())),
+            None => ui.tooltip_text("Click for options"),
+        }
+        if ui.is_mouse_clicked(imgui::MouseButton::Left) {
+            ui.open_popup(sender_popup);
+        }
+    }
+    ui.same_line_with_spacing(0.0, 0.0);
+    ui.text_colored(msg_color, ": ");
+    hovered | ui.is_item_hovered()
+}
+
+#[allow(clippy::too_many_arguments)]
 fn render_chat_area(
     ui: &imgui::Ui,
     messages: &[ChatMessage],
@@ -1791,6 +3301,12 @@ fn render_chat_area(
     translate_requests: &mut Vec<(u64, Vec<TextSegment>)>,
     glossary: &glossary::Glossary,
     app_language: &str,
+    source_filter: &str,
+    show_source: bool,
+    units: &HashMap<u64, player::UnitDetails>,
+    sender_filter: &str,
+    muted: &[String],
+    sender_actions: &mut Vec<SenderAction>,
 ) {
     let id = format!("chat_area_{}", tab_idx);
     let child_size = [0.0, -1.0f32];
@@ -1805,7 +3321,8 @@ fn render_chat_area(
 
         let filtered: Vec<&ChatMessage> = messages
             .iter()
-            .filter(|m| tab.matches(m.message_type))
+            .filter(|m| tab.matches(m.message_type) && matches_source(m, source_filter))
+            .filter(|m| matches_sender(m, sender_filter, muted))
             .collect();
 
         if filtered.is_empty() {
@@ -1815,6 +3332,7 @@ fn render_chat_area(
                 let msg_color = msg.message_type.color();
                 let line = msg.display_line();
                 let popup_id = format!("msg_ctx_{}_{}", tab_idx, index);
+                let sender_popup = format!("sender_ctx_{}_{}", tab_idx, index);
                 let mut line_hovered = false;
 
                 // [T] translate button
@@ -1822,22 +3340,24 @@ fn render_chat_area(
                 let btn_id = format!("T##t_{}_{}", tab_idx, index);
                 if let Some(TranslationEntry::Pending) = entry {
                     ui.text_disabled("[...]");
-                } else if has_translation_service {
-                    if ui.small_button(&btn_id) {
-                        translate_requests.push((msg.id, msg.segments.clone()));
-                    }
+                } else if has_translation_service && ui.small_button(&btn_id) {
+                    translate_requests.push((msg.id, msg.segments.clone()));
                 }
                 ui.same_line();
 
+                // Per-client marker in the merged view
+                if show_source && !msg.source.is_empty() {
+                    ui.text_colored(source_color(&msg.source), format!("[{}]", msg.source));
+                    ui.same_line();
+                }
+
                 if msg.has_links() {
                     // Rich rendering: prefix + inline colored segments
-                    let prefix = msg.display_prefix();
-                    ui.text_colored(msg_color, &prefix);
-                    if ui.is_item_hovered() {
+                    if render_prefix(ui, msg, msg_color, units, &sender_popup) {
                         line_hovered = true;
                     }
 
-                    for seg in &msg.segments {
+                    for (seg_idx, seg) in msg.segments.iter().enumerate() {
                         match seg {
                             TextSegment::Plain(text) => {
                                 if render_plain_with_glossary(
@@ -1847,9 +3367,14 @@ fn render_chat_area(
                                 }
                             }
                             TextSegment::WowLink {
-                                link_type,
                                 display_name,
                                 color,
+                                ..
+                            }
+                            | TextSegment::Player {
+                                display_name,
+                                color,
+                                ..
                             } => {
                                 let prev_end_x = ui.item_rect_max()[0];
                                 let tok_w = ui.calc_text_size(display_name)[0];
@@ -1859,12 +3384,38 @@ fn render_chat_area(
                                     ui.same_line_with_spacing(0.0, 0.0);
                                 }
                                 ui.text_colored(*color, display_name);
-                                if ui.is_item_hovered() {
+                                let hovered = ui.is_item_hovered();
+                                if hovered {
                                     line_hovered = true;
-                                    let url = link_type.wowhead_url(display_name);
-                                    ui.tooltip_text(&url);
-                                    if ui.is_mouse_clicked(imgui::MouseButton::Left) {
-                                        open_url(&url);
+                                }
+                                let clicked =
+                                    hovered && ui.is_mouse_clicked(imgui::MouseButton::Left);
+                                if let TextSegment::Player { name, .. } = seg {
+                                    let player_popup =
+                                        format!("player_ctx_{}_{}_{}", tab_idx, index, seg_idx);
+                                    if hovered {
+                                        ui.tooltip_text("Click for options");
+                                    }
+                                    if clicked {
+                                        ui.open_popup(&player_popup);
+                                    }
+                                    render_sender_menu(
+                                        ui,
+                                        &player_popup,
+                                        name,
+                                        muted,
+                                        clipboard,
+                                        sender_actions,
+                                    );
+                                } else if let TextSegment::WowLink { link_type, .. } = seg {
+                                    if hovered {
+                                        let url = link_type.wowhead_url(display_name);
+                                        let mut tip = link_type.details();
+                                        tip.push(url.clone());
+                                        ui.tooltip_text(tip.join("\n"));
+                                        if clicked {
+                                            open_url(&url);
+                                        }
                                     }
                                 }
                             }
@@ -1872,9 +3423,7 @@ fn render_chat_area(
                     }
                 } else {
                     // Simple rendering with glossary highlights
-                    let prefix = msg.display_prefix();
-                    ui.text_colored(msg_color, &prefix);
-                    if ui.is_item_hovered() {
+                    if render_prefix(ui, msg, msg_color, units, &sender_popup) {
                         line_hovered = true;
                     }
                     if render_plain_with_glossary(
@@ -1884,6 +3433,15 @@ fn render_chat_area(
                     }
                 }
 
+                render_sender_menu(
+                    ui,
+                    &sender_popup,
+                    &msg.sender_name,
+                    muted,
+                    clipboard,
+                    sender_actions,
+                );
+
                 // Right-click context menu (works for both simple and rich rendering)
                 if line_hovered && ui.is_mouse_released(imgui::MouseButton::Right) {
                     ui.open_popup(&popup_id);
@@ -1899,6 +3457,13 @@ fn render_chat_area(
                             cb.copy(&msg.text);
                         }
                     }
+                    if msg.has_links() && ui.selectable("Copy With Game Links") {
+                        if let Some(ref mut cb) = clipboard {
+                            let text: String =
+                                msg.segments.iter().map(|s| s.to_wow_string()).collect();
+                            cb.copy(&text);
+                        }
+                    }
                 }
 
                 // Show translation result below the message
@@ -1906,13 +3471,13 @@ fn render_chat_area(
                     Some(TranslationEntry::Done(translated)) => {
                         ui.text_colored(
                             [0.6, 0.8, 0.6, 1.0],
-                            &format!("  \u{21B3} {}", translated),
+                            format!("  \u{21B3} {}", translated),
                         );
                     }
                     Some(TranslationEntry::Error(err)) => {
                         ui.text_colored(
                             [1.0, 0.3, 0.3, 1.0],
-                            &format!("  \u{21B3} Translation error: {}", err),
+                            format!("  \u{21B3} Translation error: {}", err),
                         );
                     }
                     _ => {}
@@ -1986,7 +3551,7 @@ fn append_chat_history(messages: &[ChatMessage]) {
     use std::io::Write;
     let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
     for msg in messages {
-        let _ = writeln!(file, "[{}] {}", now, msg.display_line());
+        let _ = writeln!(file, "[{}] [{}] {}", now, msg.source, msg.display_line());
     }
 }
 
@@ -5,10 +5,35 @@ pub trait ProcessMemoryReader {
     fn attach(&mut self, pid: u32) -> io::Result<()>;
     fn read_memory(&self, address: usize, size: usize) -> io::Result<Vec<u8>>;
     fn detach(&mut self) -> io::Result<()>;
+    #[allow(dead_code)]
     fn is_attached(&self) -> bool;
 
-    /// Scan process memory for a byte pattern. Returns addresses of all matches.
-    fn scan_for_bytes(&self, _needle: &[u8]) -> io::Result<Vec<usize>> {
+    /// Read several `(address, size)` ranges in one go. Each result may be short like
+    /// `read_memory`; a range that cannot be read at all comes back empty. Fails only
+    /// if every range failed. The default reads the ranges one by one.
+    fn read_memory_ranges(&self, ranges: &[(usize, usize)]) -> io::Result<Vec<Vec<u8>>> {
+        read_ranges_one_by_one(self, ranges)
+    }
+
+    /// Scan `[start, end)` for a byte pattern. Returns addresses of all matches.
+    fn scan_range_for_bytes(
+        &self,
+        needle: &[u8],
+        start: usize,
+        end: usize,
+    ) -> io::Result<Vec<usize>> {
+        self.scan_range_limited(needle, start, end, SCAN_MAX_RESULTS)
+    }
+
+    /// Like `scan_range_for_bytes`, stopping after `max_results` matches instead of
+    /// the default 1000.
+    fn scan_range_limited(
+        &self,
+        _needle: &[u8],
+        _start: usize,
+        _end: usize,
+        _max_results: usize,
+    ) -> io::Result<Vec<usize>> {
         Err(io::Error::new(
             io::ErrorKind::Unsupported,
             "Memory scanning not supported on this platform",
@@ -16,6 +41,87 @@ pub trait ProcessMemoryReader {
     }
 }
 
+/// `read_memory_ranges` in terms of `read_memory`, with the same empty-on-failure rules.
+fn read_ranges_one_by_one<R: ProcessMemoryReader + ?Sized>(
+    reader: &R,
+    ranges: &[(usize, usize)],
+) -> io::Result<Vec<Vec<u8>>> {
+    let mut results = Vec::with_capacity(ranges.len());
+    let mut last_error = None;
+    for &(address, size) in ranges {
+        match reader.read_memory(address, size) {
+            Ok(data) => results.push(data),
+            Err(e) => {
+                results.push(Vec::new());
+                last_error = Some(e);
+            }
+        }
+    }
+    match last_error {
+        Some(e) if results.iter().all(|r| r.is_empty()) => Err(e),
+        _ => Ok(results),
+    }
+}
+
+// ─── Scan helpers (shared by platform readers) ──────────────────────
+
+/// WoW 3.3.5a is 32-bit; only this part of the address space is scanned.
+pub(crate) const SCAN_MIN_ADDRESS: usize = 0x10000; // Skip first 64 KB (null page area)
+pub(crate) const SCAN_MAX_ADDRESS: usize = 0x7FFF_0000;
+pub(crate) const SCAN_MAX_RESULTS: usize = 1000;
+const SCAN_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// Append addresses of all `needle` occurrences in `data` (read from `base`) to `results`,
+/// stopping once `max_results` is reached.
+fn find_matches(
+    data: &[u8],
+    base: usize,
+    needle: &[u8],
+    results: &mut Vec<usize>,
+    max_results: usize,
+) {
+    if data.len() < needle.len() {
+        return;
+    }
+    for i in 0..=data.len() - needle.len() {
+        if data[i] == needle[0] && data[i..i + needle.len()] == *needle {
+            results.push(base + i);
+            if results.len() >= max_results {
+                break;
+            }
+        }
+    }
+}
+
+/// Scan `[base, base + size)` chunk by chunk, overlapping chunk boundaries so that
+/// matches spanning two chunks are not missed. Returns the number of bytes read.
+fn scan_region(
+    reader: &dyn ProcessMemoryReader,
+    base: usize,
+    size: usize,
+    needle: &[u8],
+    results: &mut Vec<usize>,
+    max_results: usize,
+) -> u64 {
+    let mut bytes_scanned = 0u64;
+    let mut off = 0;
+    while off < size && results.len() < max_results {
+        let read_size = SCAN_CHUNK_SIZE.min(size - off);
+        let read_addr = base + off;
+        if let Ok(data) = reader.read_memory(read_addr, read_size) {
+            find_matches(&data, read_addr, needle, results, max_results);
+            bytes_scanned += data.len() as u64;
+        }
+        // Overlap at chunk boundaries to catch cross-boundary matches
+        if needle.len() > 1 && off + SCAN_CHUNK_SIZE < size {
+            off += SCAN_CHUNK_SIZE - (needle.len() - 1);
+        } else {
+            off += SCAN_CHUNK_SIZE;
+        }
+    }
+    bytes_scanned
+}
+
 #[cfg(windows)]
 mod windows_impl {
     use super::*;
@@ -35,6 +141,10 @@ mod windows_impl {
         }
     }
 
+    // The process handle is a plain kernel handle; ReadProcessMemory/VirtualQueryEx may
+    // be called with it from any thread (the polling thread owns reads while attached).
+    unsafe impl Send for WindowsMemoryReader {}
+
     impl ProcessMemoryReader for WindowsMemoryReader {
         fn attach(&mut self, pid: u32) -> io::Result<()> {
             self.detach()?;
@@ -90,7 +200,13 @@ mod windows_impl {
             self.handle.is_some()
         }
 
-        fn scan_for_bytes(&self, needle: &[u8]) -> io::Result<Vec<usize>> {
+        fn scan_range_limited(
+            &self,
+            needle: &[u8],
+            start: usize,
+            end: usize,
+            max_results: usize,
+        ) -> io::Result<Vec<usize>> {
             let handle = self
                 .handle
                 .ok_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "Not attached"))?;
@@ -99,15 +215,19 @@ mod windows_impl {
             }
 
             let mut results = Vec::new();
-            let mut address: usize = 0x10000; // Skip first 64 KB (null page area)
-            let max_address: usize = 0x7FFF_0000;
-            let max_results: usize = 1000;
+            let mut address = start.max(SCAN_MIN_ADDRESS);
+            let end = end.min(SCAN_MAX_ADDRESS);
             let mut regions_scanned: u32 = 0;
             let mut bytes_scanned: u64 = 0;
 
-            info!("Scanning process memory for {} byte pattern...", needle.len());
+            info!(
+                "Scanning process memory 0x{:X}-0x{:X} for {} byte pattern...",
+                address,
+                end,
+                needle.len()
+            );
 
-            while address < max_address && results.len() < max_results {
+            while address < end && results.len() < max_results {
                 let mut mbi = MEMORY_BASIC_INFORMATION::default();
                 let ret = unsafe {
                     VirtualQueryEx(
@@ -132,36 +252,11 @@ mod windows_impl {
                 if mbi.State == MEM_COMMIT {
                     let p = mbi.Protect.0;
                     // p != 0, not PAGE_NOACCESS(0x01), not PAGE_GUARD(0x100)
-                    if p != 0 && (p & 0x01) == 0 && (p & 0x100) == 0 {
-                        const CHUNK: usize = 4 * 1024 * 1024;
-                        let mut off = 0;
-                        while off < size && results.len() < max_results {
-                            let read_size = CHUNK.min(size - off);
-                            let read_addr = base + off;
-                            if let Ok(data) = self.read_memory(read_addr, read_size) {
-                                if data.len() >= needle.len() {
-                                    let mut i = 0;
-                                    while i <= data.len() - needle.len() {
-                                        if data[i] == needle[0]
-                                            && data[i..i + needle.len()] == *needle
-                                        {
-                                            results.push(read_addr + i);
-                                            if results.len() >= max_results {
-                                                break;
-                                            }
-                                        }
-                                        i += 1;
-                                    }
-                                }
-                                bytes_scanned += data.len() as u64;
-                            }
-                            // Overlap at chunk boundaries to catch cross-boundary matches
-                            if needle.len() > 1 && off + CHUNK < size {
-                                off += CHUNK - (needle.len() - 1);
-                            } else {
-                                off += CHUNK;
-                            }
-                        }
+                    // Clamp the region to the requested range
+                    let lo = base.max(address);
+                    let hi = next.min(end);
+                    if p != 0 && (p & 0x01) == 0 && (p & 0x100) == 0 && lo < hi {
+                        bytes_scanned += scan_region(self, lo, hi - lo, needle, &mut results, max_results);
                         regions_scanned += 1;
                     }
                 }
@@ -194,19 +289,74 @@ mod windows_impl {
 #[cfg(target_os = "linux")]
 mod linux_impl {
     use super::*;
+    use log::warn;
     use std::fs::File;
     use std::io::{Read, Seek, SeekFrom};
 
+    /// Kernel limit on iovecs per `process_vm_readv` call.
+    const IOV_MAX: usize = 1024;
+
     pub struct LinuxMemoryReader {
         mem_file: Option<File>,
+        pid: Option<u32>,
     }
 
     impl LinuxMemoryReader {
         pub fn new() -> Self {
-            Self { mem_file: None }
+            Self {
+                mem_file: None,
+                pid: None,
+            }
         }
     }
 
+    /// A readable mapping from `/proc/<pid>/maps`, clamped to the scan range.
+    struct MapRegion {
+        start: usize,
+        end: usize,
+    }
+
+    /// Parse `/proc/<pid>/maps` into readable regions clamped to `[lo, hi)`.
+    ///
+    /// Line format: `start-end perms offset dev inode [path]`, e.g.
+    /// `00400000-00c2d000 r-xp 00000000 00:00 0  /path/to/Wow.exe`.
+    fn readable_regions(pid: u32, lo: usize, hi: usize) -> io::Result<Vec<MapRegion>> {
+        let maps = std::fs::read_to_string(format!("/proc/{}/maps", pid))?;
+        let mut regions = Vec::new();
+
+        for line in maps.lines() {
+            let mut fields = line.split_whitespace();
+            let (Some(range), Some(perms)) = (fields.next(), fields.next()) else {
+                continue;
+            };
+            // Kernel pseudo-mappings that fail or fault when read via /proc/<pid>/mem.
+            let path = fields.nth(3).unwrap_or("");
+            if path == "[vvar]" || path == "[vsyscall]" {
+                continue;
+            }
+            if !perms.starts_with('r') {
+                continue;
+            }
+            let Some((start, end)) = range.split_once('-') else {
+                continue;
+            };
+            let (Ok(start), Ok(end)) = (
+                usize::from_str_radix(start, 16),
+                usize::from_str_radix(end, 16),
+            ) else {
+                continue;
+            };
+
+            let start = start.max(lo);
+            let end = end.min(hi);
+            if start < end {
+                regions.push(MapRegion { start, end });
+            }
+        }
+
+        Ok(regions)
+    }
+
     impl ProcessMemoryReader for LinuxMemoryReader {
         fn attach(&mut self, pid: u32) -> io::Result<()> {
             self.detach()?;
@@ -218,6 +368,7 @@ mod linux_impl {
             })?;
             info!("Successfully opened {}", path);
             self.mem_file = Some(file);
+            self.pid = Some(pid);
             Ok(())
         }
 
@@ -236,7 +387,88 @@ mod linux_impl {
             Ok(buffer)
         }
 
+        /// Batched read with `process_vm_readv`: one syscall for all ranges instead of a
+        /// seek + read each. The kernel stops at the first range that faults, so the
+        /// call is repeated from the range after it.
+        fn read_memory_ranges(&self, ranges: &[(usize, usize)]) -> io::Result<Vec<Vec<u8>>> {
+            let pid = self
+                .pid
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "Not attached"))?;
+            let mut results: Vec<Vec<u8>> =
+                ranges.iter().map(|&(_, size)| vec![0u8; size]).collect();
+            let mut lengths = vec![0usize; ranges.len()];
+            let mut last_error = None;
+
+            let mut next = 0;
+            while next < ranges.len() {
+                let batch = &ranges[next..(next + IOV_MAX).min(ranges.len())];
+                let local: Vec<libc::iovec> = results[next..next + batch.len()]
+                    .iter_mut()
+                    .map(|buf| libc::iovec {
+                        iov_base: buf.as_mut_ptr() as *mut libc::c_void,
+                        iov_len: buf.len(),
+                    })
+                    .collect();
+                let remote: Vec<libc::iovec> = batch
+                    .iter()
+                    .map(|&(address, size)| libc::iovec {
+                        iov_base: address as *mut libc::c_void,
+                        iov_len: size,
+                    })
+                    .collect();
+                trace!("process_vm_readv: {} ranges from #{}", batch.len(), next);
+                let ret = unsafe {
+                    libc::process_vm_readv(
+                        pid as libc::pid_t,
+                        local.as_ptr(),
+                        local.len() as libc::c_ulong,
+                        remote.as_ptr(),
+                        remote.len() as libc::c_ulong,
+                        0,
+                    )
+                };
+                if ret < 0 {
+                    let e = io::Error::last_os_error();
+                    match e.raw_os_error() {
+                        // Kernel without process_vm_readv — fall back to /proc/<pid>/mem.
+                        Some(libc::ENOSYS) => return read_ranges_one_by_one(self, ranges),
+                        // Process is gone; no point trying the other ranges.
+                        Some(libc::ESRCH) => return Err(e),
+                        _ => {}
+                    }
+                    // The first range in the batch is unreadable; skip it.
+                    debug!("process_vm_readv failed at 0x{:X}: {}", batch[0].0, e);
+                    last_error = Some(e);
+                    next += 1;
+                    continue;
+                }
+
+                // Distribute the byte count over the ranges; the first short one faulted.
+                let mut remaining = ret as usize;
+                let mut done = 0;
+                for &(_, size) in batch {
+                    let n = remaining.min(size);
+                    lengths[next + done] = n;
+                    remaining -= n;
+                    done += 1;
+                    if n < size {
+                        break;
+                    }
+                }
+                next += done.max(1);
+            }
+
+            for (buf, len) in results.iter_mut().zip(&lengths) {
+                buf.truncate(*len);
+            }
+            match last_error {
+                Some(e) if results.iter().all(|r| r.is_empty()) => Err(e),
+                _ => Ok(results),
+            }
+        }
+
         fn detach(&mut self) -> io::Result<()> {
+            self.pid = None;
             if self.mem_file.take().is_some() {
                 info!("Closed /proc/mem file");
             }
@@ -246,10 +478,72 @@ mod linux_impl {
         fn is_attached(&self) -> bool {
             self.mem_file.is_some()
         }
+
+        fn scan_range_limited(
+            &self,
+            needle: &[u8],
+            start: usize,
+            end: usize,
+            max_results: usize,
+        ) -> io::Result<Vec<usize>> {
+            let pid = self
+                .pid
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "Not attached"))?;
+            if needle.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let lo = start.max(SCAN_MIN_ADDRESS);
+            let hi = end.min(SCAN_MAX_ADDRESS);
+            let regions = readable_regions(pid, lo, hi).map_err(|e| {
+                error!("Failed to read /proc/{}/maps: {}", pid, e);
+                e
+            })?;
+            debug!("{} readable regions in /proc/{}/maps", regions.len(), pid);
+
+            let mut results = Vec::new();
+            let mut regions_scanned: u32 = 0;
+            let mut bytes_scanned: u64 = 0;
+
+            info!(
+                "Scanning process memory 0x{:X}-0x{:X} for {} byte pattern...",
+                lo,
+                hi,
+                needle.len()
+            );
+
+            for region in &regions {
+                if results.len() >= max_results {
+                    break;
+                }
+                bytes_scanned += scan_region(
+                    self,
+                    region.start,
+                    region.end - region.start,
+                    needle,
+                    &mut results,
+                    max_results,
+                );
+                regions_scanned += 1;
+            }
+
+            if results.len() >= max_results {
+                warn!("Scan capped at {} results", max_results);
+            }
+
+            info!(
+                "Scan complete: {} regions, {:.1} MB scanned, {} matches",
+                regions_scanned,
+                bytes_scanned as f64 / (1024.0 * 1024.0),
+                results.len(),
+            );
+
+            Ok(results)
+        }
     }
 }
 
-pub fn create_reader() -> Box<dyn ProcessMemoryReader> {
+pub fn create_reader() -> Box<dyn ProcessMemoryReader + Send> {
     #[cfg(windows)]
     {
         Box::new(windows_impl::WindowsMemoryReader::new())
@@ -0,0 +1,180 @@
+use log::debug;
+use std::collections::HashMap;
+use std::io;
+
+use crate::guid::{Guid, GuidKind};
+use crate::memory::ProcessMemoryReader;
+use crate::offsets::Offsets;
+use crate::pointer::{is_valid_ptr, read_ptr};
+
+// ─── Name Store ──────────────────────────────────────────────────────
+//
+// The client keeps the name of every player it has seen in a hash table keyed by
+// the low 32 bits of the GUID (docs/offsets.md §4.2). Each bucket is 12 bytes: the
+// offset of the "next" link inside an entry, then at +8 the first entry. A link with
+// the low bit set ends the chain. Entries start with the low GUID and hold the name
+// at `name_string_offset`.
+
+const BUCKET_SIZE: usize = 12;
+const BUCKET_FIRST_ENTRY: usize = 8;
+/// Longest chain walked before giving up (a sane table has a handful per bucket).
+const MAX_CHAIN_LEN: usize = 256;
+const MAX_NAME_LEN: usize = 48;
+/// Names cached before the cache is cleared.
+const MAX_CACHED_NAMES: usize = 4096;
+
+fn read_u32_mem(reader: &dyn ProcessMemoryReader, addr: usize) -> io::Result<u32> {
+    let data = reader.read_memory(addr, 4)?;
+    data.get(..4)
+        .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "short read"))
+}
+
+/// Look `guid` up in the client's Name Store. `Ok(None)` if it is not cached there
+/// (yet); `Err` if the table itself cannot be read.
+pub fn lookup_name(
+    reader: &dyn ProcessMemoryReader,
+    offsets: &Offsets,
+    guid: u64,
+) -> io::Result<Option<String>> {
+    let low = guid as u32;
+    let mask = read_u32_mem(reader, offsets.name_store + offsets.name_mask_offset)?;
+    if mask == u32::MAX {
+        return Ok(None);
+    }
+    let buckets = read_ptr(reader, offsets.name_store + offsets.name_base_offset)?;
+    let bucket = buckets + BUCKET_SIZE * (mask & low) as usize;
+    let link_offset = read_u32_mem(reader, bucket)? as usize;
+
+    let mut entry = read_u32_mem(reader, bucket + BUCKET_FIRST_ENTRY)? as usize;
+    for _ in 0..MAX_CHAIN_LEN {
+        if entry & 1 != 0 || !is_valid_ptr(entry) {
+            return Ok(None);
+        }
+        if read_u32_mem(reader, entry)? == low {
+            let data = reader.read_memory(entry + offsets.name_string_offset, MAX_NAME_LEN)?;
+            let end = data.iter().position(|&b| b == 0).unwrap_or(data.len());
+            let name = String::from_utf8_lossy(&data[..end]).into_owned();
+            return Ok((!name.is_empty()).then_some(name));
+        }
+        entry = read_u32_mem(reader, entry + link_offset + 4)? as usize;
+    }
+    debug!("Name Store chain for 0x{:X} too long, giving up", guid);
+    Ok(None)
+}
+
+/// GUID → name results from the Name Store, so each sender is looked up once.
+pub struct NameCache {
+    names: HashMap<u64, String>,
+}
+
+impl NameCache {
+    pub fn new() -> Self {
+        Self {
+            names: HashMap::new(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.names.clear();
+    }
+
+    /// Name of the player `guid` (other kinds are never in the store). Misses are not
+    /// cached: the client adds a name once the server answers its query, usually a
+    /// moment later.
+    pub fn resolve(
+        &mut self,
+        reader: &dyn ProcessMemoryReader,
+        offsets: &Offsets,
+        guid: Guid,
+    ) -> Option<String> {
+        if guid.kind() != GuidKind::Player {
+            return None;
+        }
+        let guid = guid.raw();
+        if let Some(name) = self.names.get(&guid) {
+            return Some(name.clone());
+        }
+        let name = match lookup_name(reader, offsets, guid) {
+            Ok(name) => name?,
+            Err(e) => {
+                debug!("Name Store lookup for 0x{:X} failed: {}", guid, e);
+                return None;
+            }
+        };
+        if self.names.len() >= MAX_CACHED_NAMES {
+            self.names.clear();
+        }
+        self.names.insert(guid, name.clone());
+        Some(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_memory::{FakeWowMemory, NAME_LINK_OFFSET};
+
+    const ALICE: u64 = 0x0000_0000_0000_0005;
+    /// Same bucket as `ALICE` in the fake 4-bucket table.
+    const BOB: u64 = 0x0000_0000_0000_0009;
+
+    fn store() -> (FakeWowMemory, usize, usize) {
+        let mut memory = FakeWowMemory::new(Offsets::default());
+        let alice = memory.add_cached_name(ALICE, "Alice");
+        let bob = memory.add_cached_name(BOB, "Bob");
+        (memory, alice, bob)
+    }
+
+    #[test]
+    fn lookup_hit() {
+        let (memory, _, _) = store();
+        let offsets = memory.offsets();
+        // Bob heads the chain, Alice is reached through his link.
+        assert_eq!(lookup_name(&memory, &offsets, BOB).unwrap().as_deref(), Some("Bob"));
+        assert_eq!(lookup_name(&memory, &offsets, ALICE).unwrap().as_deref(), Some("Alice"));
+    }
+
+    #[test]
+    fn lookup_miss() {
+        let (memory, _, _) = store();
+        let offsets = memory.offsets();
+        // End of a non-empty chain, and an empty bucket.
+        assert_eq!(lookup_name(&memory, &offsets, 0x0000_0000_0000_000D).unwrap(), None);
+        assert_eq!(lookup_name(&memory, &offsets, 0x0000_0000_0000_0006).unwrap(), None);
+    }
+
+    #[test]
+    fn lookup_unset_table() {
+        let (mut memory, _, _) = store();
+        let offsets = memory.offsets();
+        memory.write_u32(offsets.name_store + offsets.name_mask_offset, u32::MAX);
+        assert_eq!(lookup_name(&memory, &offsets, ALICE).unwrap(), None);
+    }
+
+    #[test]
+    fn lookup_stops_at_low_bit() {
+        let (mut memory, alice, bob) = store();
+        let offsets = memory.offsets();
+        // Bob's link still points at Alice, but with the end-of-chain bit set.
+        memory.write_u32(bob + NAME_LINK_OFFSET + 4, alice as u32 | 1);
+        assert_eq!(lookup_name(&memory, &offsets, ALICE).unwrap(), None);
+        assert_eq!(lookup_name(&memory, &offsets, BOB).unwrap().as_deref(), Some("Bob"));
+    }
+
+    #[test]
+    fn resolve_players_only() {
+        let (mut memory, _, _) = store();
+        let offsets = memory.offsets();
+        let mut cache = NameCache::new();
+        assert_eq!(cache.resolve(&memory, &offsets, Guid(ALICE)).as_deref(), Some("Alice"));
+        assert_eq!(cache.resolve(&memory, &offsets, Guid(0x0000_0000_0000_000D)), None);
+        // A creature with the same low GUID as a cached player is never looked up.
+        assert_eq!(cache.resolve(&memory, &offsets, Guid(0xF130_0000_0000_0005)), None);
+
+        // Hits are cached; misses are looked up again.
+        memory.write_u32(offsets.name_store + offsets.name_mask_offset, u32::MAX);
+        assert_eq!(cache.resolve(&memory, &offsets, Guid(ALICE)).as_deref(), Some("Alice"));
+        assert_eq!(cache.resolve(&memory, &offsets, Guid(BOB)), None);
+    }
+}
@@ -1,8 +1,11 @@
 // WoW 3.3.5a (Build 12340) — Memory Offsets
 // See docs/offsets.md for full documentation.
 
+use serde::{Deserialize, Serialize};
+
 // ── Chat Buffer (circular, 60 entries) ──────────────────────────────
 pub const CHAT_BUFFER_START: usize = 0x00B75A60;
+pub const CHAT_BUFFER_START_ALT: usize = 0x00B75A58; // start given by some sources, 8 bytes earlier
 pub const CHAT_MESSAGE_STRIDE: usize = 0x17C0; // 6080 bytes per message
 pub const CHAT_BUFFER_COUNT: usize = 0x00BCEFEC; // next-write index (u32) — may be wrong for some builds
 pub const CHAT_BUFFER_COUNT_ALT: usize = 0x00B66EDC; // alternative count address
@@ -32,8 +35,108 @@ pub const FIRST_OBJECT_OFFSET: usize = 0xAC;
 pub const LOCAL_GUID_OFFSET: usize = 0xC0;
 pub const NEXT_OBJECT_OFFSET: usize = 0x3C;
 pub const OBJECT_GUID_OFFSET: usize = 0x30;
+pub const OBJECT_TYPE_OFFSET: usize = 0x14; // u32, TYPEID_* (4 = player)
 pub const DESCRIPTOR_PTR_OFFSET: usize = 0x08;
 
 // ── Unit/Player descriptor field offsets (build 12340 / 3.3.5a) ─────
+pub const UNIT_FIELD_BYTES_0: usize = 0x5C; // u8 race, class, gender, power type; index 0x17
 pub const UNIT_FIELD_LEVEL: usize = 0xD8; // u32, descriptor index 0x36
 pub const PLAYER_FIELD_COINAGE: usize = 0x1248; // u32 (copper), descriptor index 0x0492
+
+// ── Name Store (GUID → name hash table) ─────────────────────────────
+pub const NAME_STORE: usize = 0x00C5D940; // hash table header
+pub const NAME_BASE_OFFSET: usize = 0x1C; // ptr to buckets, 12 bytes each
+pub const NAME_STRING_OFFSET: usize = 0x20; // char[] within an entry
+pub const NAME_MASK_OFFSET: usize = 0x24; // u32 bucket mask
+
+// ── Runtime offsets ─────────────────────────────────────────────────
+
+/// Addresses that move between client builds and repacks. Loaded from an offset
+/// profile (`profiles.rs`); `signature::resolve_offsets` may refine the chat/player
+/// addresses at attach time. Defaults are the 3.3.5a constants above.
+///
+/// The chat message layout (stride and field offsets) is the same across 3.3.x
+/// builds and stays compile-time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Offsets {
+    pub chat_buffer_start: usize,
+    pub chat_buffer_count: usize,
+    pub chat_buffer_count_alt: usize,
+    pub player_name: usize,
+    pub player_guid: usize,
+    pub realm_name: usize,
+    pub client_connection: usize,
+    pub object_manager_offset: usize,
+    pub first_object_offset: usize,
+    pub local_guid_offset: usize,
+    pub next_object_offset: usize,
+    pub object_guid_offset: usize,
+    pub object_type_offset: usize,
+    pub descriptor_ptr_offset: usize,
+    pub unit_field_bytes_0: usize,
+    pub unit_field_level: usize,
+    pub player_field_coinage: usize,
+    pub name_store: usize,
+    pub name_base_offset: usize,
+    pub name_string_offset: usize,
+    pub name_mask_offset: usize,
+}
+
+impl Default for Offsets {
+    fn default() -> Self {
+        Self {
+            chat_buffer_start: CHAT_BUFFER_START,
+            chat_buffer_count: CHAT_BUFFER_COUNT,
+            chat_buffer_count_alt: CHAT_BUFFER_COUNT_ALT,
+            player_name: PLAYER_NAME,
+            player_guid: PLAYER_GUID,
+            realm_name: REALM_NAME,
+            client_connection: CLIENT_CONNECTION,
+            object_manager_offset: OBJECT_MANAGER_OFFSET,
+            first_object_offset: FIRST_OBJECT_OFFSET,
+            local_guid_offset: LOCAL_GUID_OFFSET,
+            next_object_offset: NEXT_OBJECT_OFFSET,
+            object_guid_offset: OBJECT_GUID_OFFSET,
+            object_type_offset: OBJECT_TYPE_OFFSET,
+            descriptor_ptr_offset: DESCRIPTOR_PTR_OFFSET,
+            unit_field_bytes_0: UNIT_FIELD_BYTES_0,
+            unit_field_level: UNIT_FIELD_LEVEL,
+            player_field_coinage: PLAYER_FIELD_COINAGE,
+            name_store: NAME_STORE,
+            name_base_offset: NAME_BASE_OFFSET,
+            name_string_offset: NAME_STRING_OFFSET,
+            name_mask_offset: NAME_MASK_OFFSET,
+        }
+    }
+}
+
+impl Offsets {
+    /// Profile fields holding absolute addresses (the rest are struct offsets), as
+    /// named in `offsets.toml`.
+    pub const ADDRESS_FIELDS: &'static [&'static str] = &[
+        "chat_buffer_start",
+        "chat_buffer_count",
+        "chat_buffer_count_alt",
+        "player_name",
+        "player_guid",
+        "realm_name",
+        "client_connection",
+        "name_store",
+    ];
+
+    /// Mutable access to an absolute address field by its `ADDRESS_FIELDS` name.
+    pub fn address_field_mut(&mut self, name: &str) -> Option<&mut usize> {
+        match name {
+            "chat_buffer_start" => Some(&mut self.chat_buffer_start),
+            "chat_buffer_count" => Some(&mut self.chat_buffer_count),
+            "chat_buffer_count_alt" => Some(&mut self.chat_buffer_count_alt),
+            "player_name" => Some(&mut self.player_name),
+            "player_guid" => Some(&mut self.player_guid),
+            "realm_name" => Some(&mut self.realm_name),
+            "client_connection" => Some(&mut self.client_connection),
+            "name_store" => Some(&mut self.name_store),
+            _ => None,
+        }
+    }
+}
@@ -2,15 +2,8 @@ use log::debug;
 use std::io;
 
 use crate::memory::ProcessMemoryReader;
-use crate::offsets;
-
-/// WoW 3.3.5a is 32-bit; valid userspace pointers are in this range.
-const MIN_VALID_PTR: usize = 0x10000;
-const MAX_VALID_PTR: usize = 0x7FFF_0000;
-
-fn is_valid_ptr(addr: usize) -> bool {
-    addr > MIN_VALID_PTR && addr < MAX_VALID_PTR
-}
+use crate::offsets::Offsets;
+use crate::pointer::{is_valid_ptr, read_ptr, PointerPath};
 
 pub struct PlayerInfo {
     pub name: String,
@@ -57,21 +50,47 @@ fn read_cstring_mem(
     Ok(String::from_utf8_lossy(&data[..null_pos]).into_owned())
 }
 
-/// Read a u32 pointer and validate it's in 32-bit userspace.
-fn read_ptr(reader: &dyn ProcessMemoryReader, addr: usize) -> io::Result<usize> {
-    let ptr = read_u32_mem(reader, addr)? as usize;
-    if !is_valid_ptr(ptr) {
-        return Err(io::Error::new(io::ErrorKind::InvalidData, "invalid pointer"));
+/// Longest object list walked (a busy city has a few hundred objects).
+const MAX_OBJECTS: u32 = 500;
+/// `TYPEID_PLAYER` at `object_type_offset`.
+const TYPEID_PLAYER: u32 = 4;
+
+/// Object Manager base: `[[client_connection] + object_manager_offset]`.
+fn object_manager(reader: &dyn ProcessMemoryReader, offsets: &Offsets) -> io::Result<usize> {
+    PointerPath::new(
+        offsets.client_connection,
+        &[offsets.object_manager_offset, 0],
+    )
+    .resolve(reader)
+}
+
+/// Call `visit` with each object base in the Object Manager list until it returns true.
+fn walk_objects(
+    reader: &dyn ProcessMemoryReader,
+    offsets: &Offsets,
+    obj_mgr: usize,
+    mut visit: impl FnMut(usize) -> io::Result<bool>,
+) -> io::Result<()> {
+    let mut current = read_ptr(reader, obj_mgr + offsets.first_object_offset)?;
+    let mut iterations = 0u32;
+    while is_valid_ptr(current) && iterations < MAX_OBJECTS {
+        if visit(current)? {
+            return Ok(());
+        }
+        current = read_u32_mem(reader, current + offsets.next_object_offset)? as usize;
+        iterations += 1;
     }
-    Ok(ptr)
+    Ok(())
 }
 
 /// Find the local player's object base address by traversing the Object Manager linked list.
-fn find_local_player_base(reader: &dyn ProcessMemoryReader) -> io::Result<usize> {
-    let client_conn = read_ptr(reader, offsets::CLIENT_CONNECTION)?;
-    let obj_mgr = read_ptr(reader, client_conn + offsets::OBJECT_MANAGER_OFFSET)?;
+fn find_local_player_base(
+    reader: &dyn ProcessMemoryReader,
+    offsets: &Offsets,
+) -> io::Result<usize> {
+    let obj_mgr = object_manager(reader, offsets)?;
 
-    let local_guid = read_u64_mem(reader, obj_mgr + offsets::LOCAL_GUID_OFFSET)?;
+    let local_guid = read_u64_mem(reader, obj_mgr + offsets.local_guid_offset)?;
     if local_guid == 0 {
         return Err(io::Error::new(
             io::ErrorKind::NotFound,
@@ -79,39 +98,141 @@ fn find_local_player_base(reader: &dyn ProcessMemoryReader) -> io::Result<usize>
         ));
     }
 
-    let mut current = read_ptr(reader, obj_mgr + offsets::FIRST_OBJECT_OFFSET)?;
-    let mut iterations = 0u32;
-    const MAX_ITERATIONS: u32 = 500;
+    let mut found = None;
+    walk_objects(reader, offsets, obj_mgr, |object| {
+        if read_u64_mem(reader, object + offsets.object_guid_offset)? == local_guid {
+            found = Some(object);
+        }
+        Ok(found.is_some())
+    })?;
+    found.ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "local player object not found"))
+}
+
+/// Level, race and class of a player object seen in the Object Manager.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitDetails {
+    pub level: u32,
+    pub race: u8,
+    pub class: u8,
+}
 
-    while is_valid_ptr(current) && iterations < MAX_ITERATIONS {
-        let guid = read_u64_mem(reader, current + offsets::OBJECT_GUID_OFFSET)?;
-        if guid == local_guid {
-            return Ok(current);
+impl UnitDetails {
+    pub fn race_name(&self) -> &'static str {
+        match self.race {
+            1 => "Human",
+            2 => "Orc",
+            3 => "Dwarf",
+            4 => "Night Elf",
+            5 => "Undead",
+            6 => "Tauren",
+            7 => "Gnome",
+            8 => "Troll",
+            10 => "Blood Elf",
+            11 => "Draenei",
+            _ => "Unknown race",
+        }
+    }
+
+    pub fn class_name(&self) -> &'static str {
+        match self.class {
+            1 => "Warrior",
+            2 => "Paladin",
+            3 => "Hunter",
+            4 => "Rogue",
+            5 => "Priest",
+            6 => "Death Knight",
+            7 => "Shaman",
+            8 => "Mage",
+            9 => "Warlock",
+            11 => "Druid",
+            _ => "Unknown class",
         }
-        current = read_u32_mem(reader, current + offsets::NEXT_OBJECT_OFFSET)? as usize;
-        iterations += 1;
     }
 
-    Err(io::Error::new(
-        io::ErrorKind::NotFound,
-        "local player object not found",
-    ))
+    /// Standard class color (`RAID_CLASS_COLORS`), `None` for an unknown class.
+    pub fn class_color(&self) -> Option<[f32; 4]> {
+        let rgb = match self.class {
+            1 => [0.78, 0.61, 0.43],
+            2 => [0.96, 0.55, 0.73],
+            3 => [0.67, 0.83, 0.45],
+            4 => [1.0, 0.96, 0.41],
+            5 => [1.0, 1.0, 1.0],
+            6 => [0.77, 0.12, 0.23],
+            7 => [0.0, 0.44, 0.87],
+            8 => [0.41, 0.80, 0.94],
+            9 => [0.58, 0.51, 0.79],
+            11 => [1.0, 0.49, 0.04],
+            _ => return None,
+        };
+        Some([rgb[0], rgb[1], rgb[2], 1.0])
+    }
+
+    /// "Level 80 Human Paladin".
+    pub fn summary(&self) -> String {
+        format!(
+            "Level {} {} {}",
+            self.level,
+            self.race_name(),
+            self.class_name()
+        )
+    }
+}
+
+/// Read GUID, level, race and class of every player object in range (including
+/// the local player).
+pub fn read_nearby_players(
+    reader: &dyn ProcessMemoryReader,
+    offsets: &Offsets,
+) -> io::Result<Vec<(u64, UnitDetails)>> {
+    let obj_mgr = object_manager(reader, offsets)?;
+    let mut players = Vec::new();
+    walk_objects(reader, offsets, obj_mgr, |object| {
+        if read_u32_mem(reader, object + offsets.object_type_offset)? != TYPEID_PLAYER {
+            return Ok(false);
+        }
+        let guid = read_u64_mem(reader, object + offsets.object_guid_offset)?;
+        // A player whose descriptor is not readable yet is skipped, not fatal.
+        let Ok(descriptor) = read_ptr(reader, object + offsets.descriptor_ptr_offset) else {
+            return Ok(false);
+        };
+        let level = read_u32_mem(reader, descriptor + offsets.unit_field_level).unwrap_or(0);
+        let bytes = read_u32_mem(reader, descriptor + offsets.unit_field_bytes_0)
+            .unwrap_or(0)
+            .to_le_bytes();
+        players.push((
+            guid,
+            UnitDetails {
+                level,
+                race: bytes[0],
+                class: bytes[1],
+            },
+        ));
+        Ok(false)
+    })?;
+    Ok(players)
+}
+
+/// Read the logged-in character name. Returns None at the login screen.
+pub fn read_player_name(reader: &dyn ProcessMemoryReader, offsets: &Offsets) -> Option<String> {
+    read_cstring_mem(reader, offsets.player_name, 50)
+        .ok()
+        .filter(|name| !name.is_empty())
 }
 
 /// Read current player info from process memory.
 /// Returns None if the player is not logged in or data is unavailable.
-pub fn read_player_info(reader: &dyn ProcessMemoryReader) -> Option<PlayerInfo> {
-    let name = read_cstring_mem(reader, offsets::PLAYER_NAME, 50).unwrap_or_default();
-    let realm = read_cstring_mem(reader, offsets::REALM_NAME, 50).unwrap_or_default();
+pub fn read_player_info(reader: &dyn ProcessMemoryReader, offsets: &Offsets) -> Option<PlayerInfo> {
+    let name = read_cstring_mem(reader, offsets.player_name, 50).unwrap_or_default();
+    let realm = read_cstring_mem(reader, offsets.realm_name, 50).unwrap_or_default();
 
-    let (level, copper) = match find_local_player_base(reader) {
+    let (level, copper) = match find_local_player_base(reader, offsets) {
         Ok(player_base) => {
-            match read_ptr(reader, player_base + offsets::DESCRIPTOR_PTR_OFFSET) {
+            match read_ptr(reader, player_base + offsets.descriptor_ptr_offset) {
                 Ok(descriptor_ptr) => {
-                    let level = read_u32_mem(reader, descriptor_ptr + offsets::UNIT_FIELD_LEVEL)
+                    let level = read_u32_mem(reader, descriptor_ptr + offsets.unit_field_level)
                         .unwrap_or(0);
                     let copper =
-                        read_u32_mem(reader, descriptor_ptr + offsets::PLAYER_FIELD_COINAGE)
+                        read_u32_mem(reader, descriptor_ptr + offsets.player_field_coinage)
                             .unwrap_or(0);
                     // Sanity: level 1-80 for WotLK, money < ~214k gold (u32 max)
                     let level = if level > 0 && level <= 80 { level } else { 0 };
@@ -137,3 +258,59 @@ pub fn read_player_info(reader: &dyn ProcessMemoryReader) -> Option<PlayerInfo>
         copper,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_memory::{FakeWowMemory, TYPEID_GAMEOBJECT, TYPEID_UNIT};
+
+    #[test]
+    fn nearby_players_skip_other_objects() {
+        let mut memory = FakeWowMemory::new(Offsets::default());
+        memory.add_nearby_player(0x0000_0000_0000_0007, 72, 11, 7);
+        memory.add_object(0xF130_0000_0000_0001, TYPEID_UNIT);
+        memory.add_object(0xF110_0000_0000_0002, TYPEID_GAMEOBJECT);
+        memory.add_nearby_player(0x0000_0000_0000_0008, 80, 1, 2);
+
+        let players = read_nearby_players(&memory, &memory.offsets()).unwrap();
+        // Newest object first, as the fake links them at the head of the list.
+        assert_eq!(
+            players,
+            [
+                (0x8, UnitDetails { level: 80, race: 1, class: 2 }),
+                (0x7, UnitDetails { level: 72, race: 11, class: 7 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn nearby_players_unreadable_descriptor() {
+        let mut memory = FakeWowMemory::new(Offsets::default());
+        let offsets = memory.offsets();
+        memory.add_nearby_player(0x0000_0000_0000_0007, 72, 11, 7);
+        // No descriptor pointer yet: skipped.
+        let missing = memory.add_object(0x0000_0000_0000_0008, TYPEID_PLAYER);
+        memory.write_u32(missing + offsets.descriptor_ptr_offset, 0);
+        // A descriptor pointer into unmapped memory: listed without details.
+        let unmapped = memory.add_object(0x0000_0000_0000_0009, TYPEID_PLAYER);
+        memory.write_u32(unmapped + offsets.descriptor_ptr_offset, 0x2000_0000);
+
+        let players = read_nearby_players(&memory, &offsets).unwrap();
+        let empty = UnitDetails { level: 0, race: 0, class: 0 };
+        assert_eq!(
+            players,
+            [(0x9, empty), (0x7, UnitDetails { level: 72, race: 11, class: 7 })]
+        );
+    }
+
+    #[test]
+    fn nearby_players_capped() {
+        let mut memory = FakeWowMemory::new(Offsets::default());
+        for guid in 1..=MAX_OBJECTS as u64 + 10 {
+            memory.add_nearby_player(guid, 80, 1, 1);
+        }
+        let players = read_nearby_players(&memory, &memory.offsets()).unwrap();
+        assert_eq!(players.len(), MAX_OBJECTS as usize);
+        assert_eq!(players[0].0, MAX_OBJECTS as u64 + 10);
+    }
+}
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io;
+
+use crate::memory::ProcessMemoryReader;
+
+// ─── Pointer paths ───────────────────────────────────────────────────
+//
+// A base address followed by offsets, as Cheat Engine writes them:
+// `[[base] + o1] + o2` reads the pointer at `base`, adds `o1`, reads the pointer
+// there and adds `o2`. The object manager walk and values declared in
+// `offsets.toml` (`[[profile.pointer]]`) are resolved the same way.
+
+/// WoW 3.3.5a is 32-bit; valid userspace pointers are in this range.
+const MIN_VALID_PTR: usize = 0x10000;
+const MAX_VALID_PTR: usize = 0x7FFF_0000;
+/// Longest string read for a `text` value.
+const MAX_TEXT_LEN: usize = 256;
+
+pub fn is_valid_ptr(addr: usize) -> bool {
+    addr > MIN_VALID_PTR && addr < MAX_VALID_PTR
+}
+
+/// Read a u32 pointer and validate it's in 32-bit userspace.
+pub fn read_ptr(reader: &dyn ProcessMemoryReader, addr: usize) -> io::Result<usize> {
+    let data = reader.read_memory(addr, 4)?;
+    if data.len() < 4 {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "short read"));
+    }
+    let ptr = u32::from_le_bytes([data[0], data[1], data[2], data[3]]) as usize;
+    if !is_valid_ptr(ptr) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid pointer 0x{:X} at 0x{:X}", ptr, addr),
+        ));
+    }
+    Ok(ptr)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PointerPath {
+    pub base: usize,
+    /// Offsets applied after each dereference; empty means `base` itself.
+    #[serde(default)]
+    pub offsets: Vec<usize>,
+}
+
+impl PointerPath {
+    pub fn new(base: usize, offsets: &[usize]) -> Self {
+        Self {
+            base,
+            offsets: offsets.to_vec(),
+        }
+    }
+
+    /// Address the path points to. Fails on the first pointer outside userspace or an
+    /// offset that overflows the address.
+    pub fn resolve(&self, reader: &dyn ProcessMemoryReader) -> io::Result<usize> {
+        let mut addr = self.base;
+        for &offset in &self.offsets {
+            let ptr = read_ptr(reader, addr)?;
+            addr = ptr.checked_add(offset).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("0x{:X} + 0x{:X} overflows", ptr, offset),
+                )
+            })?;
+        }
+        Ok(addr)
+    }
+}
+
+impl fmt::Display for PointerPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut path = format!("0x{:08X}", self.base);
+        for offset in &self.offsets {
+            path = format!("[{}] + 0x{:X}", path, offset);
+        }
+        f.write_str(&path)
+    }
+}
+
+/// How to read the value at the end of a path.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValueKind {
+    #[default]
+    U32,
+    U64,
+    F32,
+    /// 64-bit GUID, shown in hex.
+    Guid,
+    /// Null-terminated UTF-8 string.
+    Text,
+}
+
+/// A value declared in an offset profile, e.g. the target GUID or zone name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NamedPointer {
+    pub name: String,
+    #[serde(default)]
+    pub kind: ValueKind,
+    #[serde(flatten)]
+    pub path: PointerPath,
+}
+
+impl NamedPointer {
+    pub fn new(name: &str, kind: ValueKind, base: usize, offsets: &[usize]) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            path: PointerPath::new(base, offsets),
+        }
+    }
+
+    /// Resolve the path and read the value, formatted for display.
+    pub fn read(&self, reader: &dyn ProcessMemoryReader) -> io::Result<String> {
+        let addr = self.path.resolve(reader)?;
+        let len = match self.kind {
+            ValueKind::U32 | ValueKind::F32 => 4,
+            ValueKind::U64 | ValueKind::Guid => 8,
+            ValueKind::Text => MAX_TEXT_LEN,
+        };
+        let data = reader.read_memory(addr, len)?;
+        if self.kind != ValueKind::Text && data.len() < len {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "short read"));
+        }
+        Ok(match self.kind {
+            ValueKind::U32 => u32::from_le_bytes(data[..4].try_into().unwrap()).to_string(),
+            ValueKind::F32 => f32::from_le_bytes(data[..4].try_into().unwrap()).to_string(),
+            ValueKind::U64 => u64::from_le_bytes(data[..8].try_into().unwrap()).to_string(),
+            ValueKind::Guid => {
+                format!("0x{:016X}", u64::from_le_bytes(data[..8].try_into().unwrap()))
+            }
+            ValueKind::Text => {
+                let end = data.iter().position(|&b| b == 0).unwrap_or(data.len());
+                String::from_utf8_lossy(&data[..end]).into_owned()
+            }
+        })
+    }
+}
@@ -0,0 +1,250 @@
+use log::{debug, error, info, warn};
+use std::io;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::chat::{ChatMessage, ChatReader};
+use crate::memory::{self, ProcessMemoryReader};
+use crate::offsets::Offsets;
+use crate::player::{self, PlayerInfo, UnitDetails};
+use crate::recording::PollRecorder;
+
+/// Memory reader shared between the UI (attach, debug tools) and the polling thread.
+pub type SharedReader = Arc<Mutex<Box<dyn ProcessMemoryReader + Send>>>;
+
+/// Lock `reader`, also after a panic on another thread: reads leave nothing half-updated
+/// behind, so a poisoned reader is still usable.
+pub fn lock_reader(reader: &SharedReader) -> MutexGuard<'_, Box<dyn ProcessMemoryReader + Send>> {
+    reader.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Address space scanned per lock in `scan_shared`.
+const SCAN_WINDOW: usize = 0x1000_0000;
+
+/// Scan the whole address space for `needle` like `scan_range_limited`, but lock the
+/// reader one `SCAN_WINDOW` at a time so polling keeps going during long scans. Only
+/// matches passing `keep` count towards `max_results`.
+pub fn scan_shared(
+    reader: &SharedReader,
+    needle: &[u8],
+    max_results: usize,
+    keep: impl Fn(usize) -> bool,
+) -> io::Result<Vec<usize>> {
+    let mut results = Vec::new();
+    let mut start = memory::SCAN_MIN_ADDRESS;
+    while start < memory::SCAN_MAX_ADDRESS && results.len() < max_results {
+        let end = (start + SCAN_WINDOW).min(memory::SCAN_MAX_ADDRESS);
+        // Overlap the next window so a match across the boundary is found once.
+        let overlap_end = (end + needle.len().saturating_sub(1)).min(memory::SCAN_MAX_ADDRESS);
+        let mut from = start;
+        loop {
+            let wanted = max_results - results.len();
+            let found = lock_reader(reader).scan_range_limited(needle, from, overlap_end, wanted)?;
+            let capped = found.len() >= wanted;
+            let last = found.last().copied();
+            results.extend(found.into_iter().filter(|&addr| addr < end && keep(addr)));
+            // The cap was reached with matches `keep` rejected: go on after the last one.
+            match last {
+                Some(last) if capped && results.len() < max_results && last < end => {
+                    from = last + 1;
+                }
+                _ => break,
+            }
+        }
+        start = end;
+    }
+    Ok(results)
+}
+
+/// How long the idle thread waits for a command before checking again.
+const IDLE_WAIT: Duration = Duration::from_secs(1);
+/// How often the Object Manager is walked for players in range (much slower than
+/// chat: it takes several reads per object).
+const NEARBY_PLAYERS_INTERVAL: Duration = Duration::from_secs(2);
+
+// ─── Request / Event types ───────────────────────────────────────────
+
+/// Events are tagged with the generation of the `start`/`stop` they belong to, so
+/// events still queued from before a restart are dropped.
+pub enum PollEvent {
+    /// New chat messages, oldest first.
+    Messages(Vec<ChatMessage>),
+    /// Player info read with each poll, sent ahead of its messages (`None` if unreadable).
+    PlayerInfo(Option<PlayerInfo>),
+    /// GUID, level, race and class of player objects in range.
+    NearbyPlayers(Vec<(u64, UnitDetails)>),
+    /// A poll failed; polling has stopped until the next `start`.
+    Failed(String),
+    /// Recording stopped because writing the file failed.
+    RecordingStopped,
+}
+
+enum WorkItem {
+    Start(Offsets, u64),
+    Stop(u64),
+    StartRecording(PollRecorder),
+    StopRecording,
+    SetInterval(Duration),
+    Shutdown,
+}
+
+// ─── Polling service ─────────────────────────────────────────────────
+
+/// Polls the chat buffer and player info on a background thread at a fixed interval,
+/// so reads neither depend on the frame rate nor stall the UI.
+pub struct PollingService {
+    work_tx: mpsc::Sender<WorkItem>,
+    /// Bumped by every `start` and `stop`; events from older generations are stale.
+    generation: u64,
+    _handle: thread::JoinHandle<()>,
+}
+
+impl PollingService {
+    /// Start the background polling thread (idle until `start`).
+    /// Returns (service, event_receiver).
+    pub fn start_thread(
+        reader: SharedReader,
+        interval_ms: u64,
+    ) -> (Self, mpsc::Receiver<(u64, PollEvent)>) {
+        let (work_tx, work_rx) = mpsc::channel::<WorkItem>();
+        let (tx, event_rx) = mpsc::channel::<(u64, PollEvent)>();
+
+        let handle = thread::spawn(move || {
+            let mut chat_reader = ChatReader::new();
+            let mut offsets: Option<Offsets> = None;
+            let mut interval = Duration::from_millis(interval_ms);
+            let mut next_poll = Instant::now();
+            let mut next_nearby = Instant::now();
+            let mut generation = 0;
+            let send = |generation: u64, event: PollEvent| {
+                let _ = tx.send((generation, event));
+            };
+            info!("Polling service started (interval {} ms)", interval_ms);
+
+            loop {
+                let timeout = if offsets.is_some() {
+                    next_poll.saturating_duration_since(Instant::now())
+                } else {
+                    IDLE_WAIT
+                };
+                match work_rx.recv_timeout(timeout) {
+                    Ok(WorkItem::Start(new_offsets, new_generation)) => {
+                        generation = new_generation;
+                        chat_reader.reset();
+                        offsets = Some(new_offsets);
+                        next_poll = Instant::now();
+                        next_nearby = Instant::now();
+                    }
+                    Ok(WorkItem::Stop(new_generation)) => {
+                        generation = new_generation;
+                        chat_reader.reset();
+                        offsets = None;
+                    }
+                    Ok(WorkItem::StartRecording(recorder)) => {
+                        chat_reader.start_recording(recorder);
+                    }
+                    Ok(WorkItem::StopRecording) => chat_reader.stop_recording(),
+                    Ok(WorkItem::SetInterval(new_interval)) => {
+                        info!("Poll interval set to {} ms", new_interval.as_millis());
+                        interval = new_interval;
+                        next_poll = Instant::now();
+                    }
+                    Ok(WorkItem::Shutdown) | Err(RecvTimeoutError::Disconnected) => {
+                        info!("Polling service shutting down");
+                        break;
+                    }
+                    Err(RecvTimeoutError::Timeout) => {
+                        next_poll = Instant::now() + interval;
+                        let Some(current) = offsets else {
+                            continue;
+                        };
+
+                        let reader = lock_reader(&reader);
+                        let was_recording = chat_reader.is_recording();
+                        let messages = match chat_reader.poll(&**reader, &current) {
+                            Ok(messages) => messages,
+                            Err(e) => {
+                                error!("Poll failed, stopping: {}", e);
+                                chat_reader.reset();
+                                offsets = None;
+                                send(generation, PollEvent::Failed(e.to_string()));
+                                continue;
+                            }
+                        };
+                        // Player info goes first so the session is labelled with the
+                        // character by the time its messages arrive.
+                        let info = player::read_player_info(&**reader, &current);
+                        send(generation, PollEvent::PlayerInfo(info));
+                        if !messages.is_empty() {
+                            send(generation, PollEvent::Messages(messages));
+                        }
+                        if was_recording && !chat_reader.is_recording() {
+                            warn!("Recording stopped after a write error");
+                            send(generation, PollEvent::RecordingStopped);
+                        }
+
+                        if Instant::now() >= next_nearby {
+                            next_nearby = Instant::now() + NEARBY_PLAYERS_INTERVAL;
+                            match player::read_nearby_players(&**reader, &current) {
+                                Ok(players) if !players.is_empty() => {
+                                    send(generation, PollEvent::NearbyPlayers(players));
+                                }
+                                Ok(_) => {}
+                                Err(e) => debug!("Nearby players not readable: {}", e),
+                            }
+                        }
+                    }
+                }
+            }
+
+            info!("Polling service thread exiting");
+        });
+
+        let service = PollingService {
+            work_tx,
+            generation: 0,
+            _handle: handle,
+        };
+
+        (service, event_rx)
+    }
+
+    /// Begin (or restart) polling with `offsets`, resetting change detection.
+    pub fn start(&mut self, offsets: Offsets) {
+        self.generation += 1;
+        let _ = self.work_tx.send(WorkItem::Start(offsets, self.generation));
+    }
+
+    /// Stop polling and reset change detection (also stops a recording).
+    pub fn stop(&mut self) {
+        self.generation += 1;
+        let _ = self.work_tx.send(WorkItem::Stop(self.generation));
+    }
+
+    /// Generation of the last `start`/`stop`; only events tagged with it are current.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn start_recording(&self, recorder: PollRecorder) {
+        let _ = self.work_tx.send(WorkItem::StartRecording(recorder));
+    }
+
+    pub fn stop_recording(&self) {
+        let _ = self.work_tx.send(WorkItem::StopRecording);
+    }
+
+    pub fn set_interval(&self, interval_ms: u64) {
+        let _ = self
+            .work_tx
+            .send(WorkItem::SetInterval(Duration::from_millis(interval_ms)));
+    }
+}
+
+impl Drop for PollingService {
+    fn drop(&mut self) {
+        let _ = self.work_tx.send(WorkItem::Shutdown);
+    }
+}
@@ -0,0 +1,283 @@
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::config::config_dir;
+use crate::memory::ProcessMemoryReader;
+use crate::offsets::Offsets;
+use crate::pointer::{NamedPointer, ValueKind};
+
+// ─── Offset profiles ─────────────────────────────────────────────────
+
+const PROFILES_FILE: &str = "offsets.toml";
+
+const PROFILES_HEADER: &str = "\
+# Offset profiles for supported WoW clients.
+#
+# On attach the client build is read from Wow.exe's version resource and the first
+# profile listing it in `builds` is used (or the first profile if none match).
+# Add a [[profile]] section to support another client without rebuilding. Missing
+# fields fall back to the 3.3.5a (12340) values.
+#
+# No profile ships for 3.3.3 (build 11723): its addresses differ from 3.3.5a and have
+# to be supplied by the user, e.g. found with Debug Tools → Offset Discovery Wizard
+# or the Memory Scanner in a profile with builds = [11723].
+#
+# use_signatures = true lets the code signature scan override chat_buffer_start,
+# chat_buffer_count and player_name when it finds a valid match. Fields listed in
+# `pinned` keep their saved value; saving an address from the Offset Discovery Wizard
+# or the Memory Scanner adds its field there.
+#
+# [[profile.pointer]] sections declare extra values shown in Debug Tools → Memory
+# Inspector. `offsets` are applied Cheat Engine style: [[base] + o1] + o2 reads the
+# pointer at `base`, adds o1, reads the pointer there and adds o2; no offsets reads
+# `base` itself. kind is u32, u64, f32, guid or text.
+
+";
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct OffsetProfile {
+    pub name: String,
+    #[serde(default)]
+    pub builds: Vec<u32>,
+    #[serde(default = "default_true")]
+    pub use_signatures: bool,
+    /// Fields saved by hand that the signature scan must not override.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pinned: Vec<String>,
+    #[serde(flatten)]
+    pub offsets: Offsets,
+    /// Extra values read through pointer paths (`[[profile.pointer]]`).
+    #[serde(rename = "pointer", default)]
+    pub pointers: Vec<NamedPointer>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct ProfilesFile {
+    #[serde(rename = "profile", default)]
+    profiles: Vec<OffsetProfile>,
+}
+
+/// 3.3.5a (12340) values beyond what the chat reader needs.
+fn default_pointers() -> Vec<NamedPointer> {
+    vec![
+        NamedPointer::new("target_guid", ValueKind::Guid, 0x00BD_07B0, &[]),
+        NamedPointer::new("zone_name", ValueKind::Text, 0x00BD_0788, &[0]),
+        NamedPointer::new("subzone_name", ValueKind::Text, 0x00BD_0784, &[0]),
+    ]
+}
+
+pub fn default_profiles() -> Vec<OffsetProfile> {
+    vec![
+        OffsetProfile {
+            name: "3.3.5a (12340)".into(),
+            builds: vec![12340],
+            use_signatures: true,
+            pinned: Vec::new(),
+            offsets: Offsets::default(),
+            pointers: default_pointers(),
+        },
+        OffsetProfile {
+            name: "Custom repack".into(),
+            builds: Vec::new(),
+            use_signatures: true,
+            pinned: Vec::new(),
+            offsets: Offsets::default(),
+            pointers: default_pointers(),
+        },
+    ]
+}
+
+fn profiles_path() -> PathBuf {
+    config_dir().join(PROFILES_FILE)
+}
+
+/// Load `offsets.toml`, creating it with the default profiles if it does not exist.
+pub fn load() -> Vec<OffsetProfile> {
+    let path = profiles_path();
+    match std::fs::read_to_string(&path) {
+        Ok(content) => match toml::from_str::<ProfilesFile>(&content) {
+            Ok(file) if !file.profiles.is_empty() => {
+                info!(
+                    "Loaded {} offset profiles from {}",
+                    file.profiles.len(),
+                    path.display()
+                );
+                file.profiles
+            }
+            Ok(_) => {
+                warn!("{} has no profiles, using defaults", path.display());
+                default_profiles()
+            }
+            Err(e) => {
+                error!("Failed to parse {}: {}", path.display(), e);
+                default_profiles()
+            }
+        },
+        Err(_) => {
+            info!("No offset profiles found, creating {}", path.display());
+            let profiles = default_profiles();
+            save(&profiles);
+            profiles
+        }
+    }
+}
+
+pub fn save(profiles: &[OffsetProfile]) {
+    let path = profiles_path();
+    let file = ProfilesFile {
+        profiles: profiles.to_vec(),
+    };
+    match toml::to_string_pretty(&file) {
+        Ok(content) => {
+            let content = format!("{}{}", PROFILES_HEADER, hex_addresses(&content));
+            if let Err(e) = std::fs::write(&path, content) {
+                error!("Failed to save offset profiles: {}", e);
+            }
+        }
+        Err(e) => error!("Failed to serialize offset profiles: {}", e),
+    }
+}
+
+/// Rewrite `key = <int>` lines and pointer `offsets = [...]` as hex so addresses
+/// read like `offsets.rs`. `builds` stays decimal.
+fn hex_addresses(content: &str) -> String {
+    content
+        .lines()
+        .map(|line| {
+            let Some((key, value)) = line.split_once(" = ") else {
+                return line.to_string();
+            };
+            if let Ok(value) = value.parse::<u64>() {
+                return format!("{} = 0x{:08X}", key, value);
+            }
+            let offsets = value
+                .strip_prefix('[')
+                .and_then(|v| v.strip_suffix(']'))
+                .filter(|_| key.trim() == "offsets")
+                .and_then(|v| {
+                    v.split(',')
+                        .map(str::trim)
+                        .filter(|n| !n.is_empty())
+                        .map(|n| n.parse::<u64>().ok().map(|n| format!("0x{:X}", n)))
+                        .collect::<Option<Vec<_>>>()
+                });
+            match offsets {
+                Some(offsets) => format!("{} = [{}]", key, offsets.join(", ")),
+                None => line.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n"
+}
+
+/// Pick a profile: the one named in config, else the first whose `builds` contains
+/// the detected build, else the first profile.
+pub fn select_profile(
+    profiles: &[OffsetProfile],
+    configured: &str,
+    version: Option<ClientVersion>,
+) -> Option<usize> {
+    if !configured.is_empty() {
+        match profiles.iter().position(|p| p.name == configured) {
+            Some(idx) => return Some(idx),
+            None => warn!("Offset profile '{}' not found, auto-detecting", configured),
+        }
+    }
+    version
+        .and_then(|v| profiles.iter().position(|p| p.builds.contains(&(v.build as u32))))
+        .or((!profiles.is_empty()).then_some(0))
+}
+
+// ─── Client version detection ────────────────────────────────────────
+
+/// Client file version, e.g. 3.3.5.12340.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+    pub build: u16,
+}
+
+impl fmt::Display for ClientVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}.{}",
+            self.major, self.minor, self.patch, self.build
+        )
+    }
+}
+
+/// `VS_FIXEDFILEINFO.dwSignature` (0xFEEF04BD), little-endian.
+const FIXED_FILE_INFO_SIGNATURE: [u8; 4] = [0xBD, 0x04, 0xEF, 0xFE];
+/// Signature + struct version + FileVersionMS + FileVersionLS.
+const FIXED_FILE_INFO_PREFIX_LEN: usize = 16;
+
+/// Wow.exe image (including .rsrc) is mapped at its fixed base 0x00400000.
+const IMAGE_START: usize = 0x0040_0000;
+const IMAGE_END: usize = 0x0200_0000;
+
+/// Read the client build from the version resource of the loaded image, falling back
+/// to the exe file on disk.
+pub fn detect_client_version(
+    reader: &dyn ProcessMemoryReader,
+    exe_path: Option<&Path>,
+) -> Option<ClientVersion> {
+    let from_memory = reader
+        .scan_range_for_bytes(&FIXED_FILE_INFO_SIGNATURE, IMAGE_START, IMAGE_END)
+        .unwrap_or_default()
+        .into_iter()
+        .find_map(|addr| {
+            let data = reader
+                .read_memory(addr, FIXED_FILE_INFO_PREFIX_LEN)
+                .ok()?;
+            parse_fixed_file_info(&data)
+        });
+    if let Some(version) = from_memory {
+        info!("Client version {} (from process memory)", version);
+        return Some(version);
+    }
+
+    let path = exe_path?;
+    let data = match std::fs::read(path) {
+        Ok(data) => data,
+        Err(e) => {
+            warn!("Cannot read {} for version info: {}", path.display(), e);
+            return None;
+        }
+    };
+    let version = data
+        .windows(FIXED_FILE_INFO_SIGNATURE.len())
+        .enumerate()
+        .filter(|(_, w)| *w == FIXED_FILE_INFO_SIGNATURE)
+        .find_map(|(i, _)| parse_fixed_file_info(&data[i..]));
+    match version {
+        Some(v) => info!("Client version {} (from {})", v, path.display()),
+        None => warn!("No version resource found in {}", path.display()),
+    }
+    version
+}
+
+fn parse_fixed_file_info(data: &[u8]) -> Option<ClientVersion> {
+    let word = |i: usize| -> Option<u32> {
+        Some(u32::from_le_bytes(data.get(i..i + 4)?.try_into().ok()?))
+    };
+    if data.get(..4)? != FIXED_FILE_INFO_SIGNATURE || word(4)? != 0x0001_0000 {
+        return None;
+    }
+    let (ms, ls) = (word(8)?, word(12)?);
+    Some(ClientVersion {
+        major: (ms >> 16) as u16,
+        minor: ms as u16,
+        patch: (ls >> 16) as u16,
+        build: ls as u16,
+    })
+}
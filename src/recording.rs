@@ -0,0 +1,279 @@
+use log::{info, warn};
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::Path;
+use std::time::Instant;
+
+use crate::chat::TOTAL_BUFFER_SIZE;
+use crate::memory::ProcessMemoryReader;
+use crate::offsets::{self, Offsets};
+use crate::snapshot::{check_fits, check_magic, read_offsets, read_u32_le, write_offsets};
+
+// ─── File format ─────────────────────────────────────────────────────
+//
+// All integers little-endian:
+//   magic    b"WCTREC02"   (version 01 had no offsets block)
+//   pid      u32
+//   offsets  u32 len + JSON-encoded `Offsets` in use while recording
+//   stride   u32          (CHAT_MESSAGE_STRIDE at recording time)
+//   slots    u32          (CHAT_BUFFER_SIZE at recording time)
+//   frames until EOF:
+//     elapsed_ms u32      (since recording start)
+//     changed    u16      (number of slots that differ from the previous frame)
+//     changed × { slot u16, bytes[stride] }
+//
+// Only changed slots are stored, so a frame where nothing was written costs nothing
+// and is skipped entirely.
+
+const RECORDING_MAGIC: &[u8; 8] = b"WCTREC02";
+pub const RECORDING_EXTENSION: &str = "wrec";
+
+// ─── Recorder ────────────────────────────────────────────────────────
+
+/// Writes the chat slots read by `ChatReader::poll` to a file.
+pub struct PollRecorder {
+    writer: BufWriter<File>,
+    started: Instant,
+    previous: Vec<u8>,
+    frames: u32,
+    keyframe_written: bool,
+}
+
+impl PollRecorder {
+    pub fn create(path: &Path, pid: u32, offsets: &Offsets) -> io::Result<Self> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(RECORDING_MAGIC)?;
+        writer.write_all(&pid.to_le_bytes())?;
+        write_offsets(&mut writer, offsets)?;
+        writer.write_all(&(offsets::CHAT_MESSAGE_STRIDE as u32).to_le_bytes())?;
+        writer.write_all(&(offsets::CHAT_BUFFER_SIZE as u32).to_le_bytes())?;
+        info!("Recording chat buffer polls to {}", path.display());
+        Ok(Self {
+            writer,
+            started: Instant::now(),
+            previous: vec![0u8; TOTAL_BUFFER_SIZE],
+            frames: 0,
+            keyframe_written: false,
+        })
+    }
+
+    /// Whether the full buffer still has to be recorded once. `poll` only reads slots
+    /// that changed, so messages already in the buffer need an initial full read.
+    pub fn needs_keyframe(&self) -> bool {
+        !self.keyframe_written
+    }
+
+    /// Append a frame for `buffer` (one full chat buffer read).
+    pub fn record(&mut self, buffer: &[u8]) -> io::Result<()> {
+        let slots: Vec<(usize, &[u8])> = (0..offsets::CHAT_BUFFER_SIZE)
+            .filter_map(|i| Some((i, buffer.get(slot_range(i))?)))
+            .collect();
+        self.record_slots(&slots)?;
+        self.keyframe_written = true;
+        Ok(())
+    }
+
+    /// Append a frame for the given `(slot, bytes)` reads; unchanged slots are dropped.
+    pub fn record_slots(&mut self, slots: &[(usize, &[u8])]) -> io::Result<()> {
+        let changed: Vec<(usize, &[u8])> = slots
+            .iter()
+            .copied()
+            .filter(|&(i, data)| {
+                i < offsets::CHAT_BUFFER_SIZE
+                    && data.len() == offsets::CHAT_MESSAGE_STRIDE
+                    && self.previous[slot_range(i)] != *data
+            })
+            .collect();
+        if changed.is_empty() {
+            return Ok(());
+        }
+
+        let elapsed_ms = self.started.elapsed().as_millis() as u32;
+        self.writer.write_all(&elapsed_ms.to_le_bytes())?;
+        self.writer.write_all(&(changed.len() as u16).to_le_bytes())?;
+        for (i, data) in changed {
+            self.writer.write_all(&(i as u16).to_le_bytes())?;
+            self.writer.write_all(data)?;
+            self.previous[slot_range(i)].copy_from_slice(data);
+        }
+        self.frames += 1;
+        Ok(())
+    }
+}
+
+impl Drop for PollRecorder {
+    fn drop(&mut self) {
+        if let Err(e) = self.writer.flush() {
+            warn!("Failed to flush recording: {}", e);
+        }
+        info!("Recording stopped ({} frames)", self.frames);
+    }
+}
+
+fn slot_range(slot: usize) -> std::ops::Range<usize> {
+    let start = slot * offsets::CHAT_MESSAGE_STRIDE;
+    start..start + offsets::CHAT_MESSAGE_STRIDE
+}
+
+// ─── Replay reader ───────────────────────────────────────────────────
+
+struct ReplayFrame {
+    elapsed_ms: u32,
+    slots: Vec<(usize, Vec<u8>)>,
+}
+
+struct ReplayState {
+    buffer: Vec<u8>,
+    next_frame: usize,
+    started: Option<Instant>,
+}
+
+/// Serves the chat buffer from a recording, applying frames as wall-clock time passes.
+///
+/// Only the chat buffer range is readable; player info reads fail, so replay runs
+/// without a `PlayerInfo`.
+pub struct ReplayMemoryReader {
+    pid: u32,
+    offsets: Offsets,
+    frames: Vec<ReplayFrame>,
+    speed: f32,
+    state: RefCell<ReplayState>,
+    attached: bool,
+}
+
+impl ReplayMemoryReader {
+    /// Load a recording. `speed` scales playback (1.0 = original timing).
+    pub fn load(path: &Path, speed: f32) -> io::Result<Self> {
+        let bytes = std::fs::read(path)?;
+        let mut file = io::Cursor::new(bytes.as_slice());
+
+        check_magic(&mut file, RECORDING_MAGIC, "recording")?;
+        let pid = read_u32_le(&mut file)?;
+        let offsets = read_offsets(&mut file)?;
+        let stride = read_u32_le(&mut file)? as usize;
+        let slots = read_u32_le(&mut file)? as usize;
+        if stride != offsets::CHAT_MESSAGE_STRIDE || slots != offsets::CHAT_BUFFER_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "recording layout {} × 0x{:X} does not match current offsets",
+                    slots, stride
+                ),
+            ));
+        }
+
+        let mut frames = Vec::new();
+        loop {
+            let elapsed_ms = match read_u32_le(&mut file) {
+                Ok(v) => v,
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(e),
+            };
+            let mut count = [0u8; 2];
+            file.read_exact(&mut count)?;
+            let count = u16::from_le_bytes(count) as usize;
+            check_fits(&file, count, 2 + stride)?;
+
+            let mut frame_slots = Vec::with_capacity(count);
+            for _ in 0..count {
+                let mut slot = [0u8; 2];
+                file.read_exact(&mut slot)?;
+                let slot = u16::from_le_bytes(slot) as usize;
+                let mut data = vec![0u8; stride];
+                file.read_exact(&mut data)?;
+                if slot < slots {
+                    frame_slots.push((slot, data));
+                }
+            }
+            frames.push(ReplayFrame {
+                elapsed_ms,
+                slots: frame_slots,
+            });
+        }
+
+        info!(
+            "Loaded recording {} (PID={}, {} frames, {:.1}s) at {}x",
+            path.display(),
+            pid,
+            frames.len(),
+            frames.last().map_or(0, |f| f.elapsed_ms) as f32 / 1000.0,
+            speed,
+        );
+
+        Ok(Self {
+            pid,
+            offsets,
+            frames,
+            speed: speed.max(0.01),
+            state: RefCell::new(ReplayState {
+                buffer: vec![0u8; TOTAL_BUFFER_SIZE],
+                next_frame: 0,
+                started: None,
+            }),
+            attached: true,
+        })
+    }
+
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Offsets the recording was made with; the chat buffer is served at their start address.
+    pub fn offsets(&self) -> Offsets {
+        self.offsets
+    }
+
+    /// Apply every frame whose (scaled) timestamp has passed. The clock starts on the first read.
+    fn advance(&self) {
+        let mut state = self.state.borrow_mut();
+        let started = *state.started.get_or_insert_with(Instant::now);
+        let now_ms = started.elapsed().as_secs_f64() * 1000.0 * self.speed as f64;
+
+        while let Some(frame) = self.frames.get(state.next_frame) {
+            if frame.elapsed_ms as f64 > now_ms {
+                break;
+            }
+            for (slot, data) in &frame.slots {
+                state.buffer[slot_range(*slot)].copy_from_slice(data);
+            }
+            state.next_frame += 1;
+        }
+    }
+}
+
+impl ProcessMemoryReader for ReplayMemoryReader {
+    fn attach(&mut self, _pid: u32) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Replay reader cannot attach to a live process",
+        ))
+    }
+
+    fn read_memory(&self, address: usize, size: usize) -> io::Result<Vec<u8>> {
+        if !self.attached {
+            return Err(io::Error::new(io::ErrorKind::NotConnected, "Not attached"));
+        }
+        let start = address.wrapping_sub(self.offsets.chat_buffer_start);
+        if start >= TOTAL_BUFFER_SIZE {
+            return Err(io::Error::other(format!(
+                "Address 0x{:X} not in recording",
+                address
+            )));
+        }
+
+        self.advance();
+        let state = self.state.borrow();
+        let end = (start + size).min(TOTAL_BUFFER_SIZE);
+        Ok(state.buffer[start..end].to_vec())
+    }
+
+    fn detach(&mut self) -> io::Result<()> {
+        self.attached = false;
+        Ok(())
+    }
+
+    fn is_attached(&self) -> bool {
+        self.attached
+    }
+}
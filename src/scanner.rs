@@ -0,0 +1,325 @@
+use log::{info, warn};
+use std::io;
+use std::sync::mpsc;
+use std::thread;
+
+use crate::polling::{self, SharedReader};
+
+// ─── Interactive memory scanner ──────────────────────────────────────
+//
+// First scan: search the whole 32-bit address space for a string or value.
+// Next scan: re-read every hit and keep those matching a filter (equal to a new
+// value, changed, unchanged, increased, decreased), like Cheat Engine. Repeating
+// next scans while the value changes in game narrows thousands of hits down to
+// the one address worth saving as an offset.
+
+/// Hits kept from a first scan. Small values like 1 match far more often; the user
+/// narrows from whatever was found.
+pub const MAX_HITS: usize = 100_000;
+/// Hits re-read per reader lock, so polling is not held up by a large next scan.
+const READ_BATCH: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueType {
+    Text,
+    U32,
+    U64,
+    Guid,
+}
+
+impl ValueType {
+    pub const ALL: &'static [ValueType] =
+        &[ValueType::Text, ValueType::U32, ValueType::U64, ValueType::Guid];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ValueType::Text => "String (UTF-8)",
+            ValueType::U32 => "u32",
+            ValueType::U64 => "u64",
+            ValueType::Guid => "GUID",
+        }
+    }
+
+    /// Byte width of a value; `None` for text (width of the needle).
+    fn width(&self) -> Option<usize> {
+        match self {
+            ValueType::Text => None,
+            ValueType::U32 => Some(4),
+            ValueType::U64 | ValueType::Guid => Some(8),
+        }
+    }
+
+    /// Parse user input into the little-endian bytes to search for. Numbers accept
+    /// decimal or `0x` hex; GUIDs are always hex (with or without `0x`).
+    pub fn parse(&self, input: &str) -> Result<Vec<u8>, String> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err("Enter a value".into());
+        }
+        let number = |hex: bool| -> Result<u64, String> {
+            let parsed = match input.strip_prefix("0x").or_else(|| input.strip_prefix("0X")) {
+                Some(digits) => u64::from_str_radix(digits, 16),
+                None if hex => u64::from_str_radix(input, 16),
+                None => input.parse::<u64>(),
+            };
+            parsed.map_err(|e| format!("Invalid number '{}': {}", input, e))
+        };
+        match self {
+            ValueType::Text => Ok(input.as_bytes().to_vec()),
+            ValueType::U32 => {
+                let value = number(false)?;
+                u32::try_from(value)
+                    .map(|v| v.to_le_bytes().to_vec())
+                    .map_err(|_| format!("{} does not fit in a u32", value))
+            }
+            ValueType::U64 => Ok(number(false)?.to_le_bytes().to_vec()),
+            ValueType::Guid => Ok(number(true)?.to_le_bytes().to_vec()),
+        }
+    }
+
+    /// Display bytes read at a hit.
+    pub fn format(&self, bytes: &[u8]) -> String {
+        match self {
+            ValueType::Text => String::from_utf8_lossy(bytes).into_owned(),
+            _ if bytes.len() < self.width().unwrap_or(0) => "??".into(),
+            ValueType::U32 => le_value(bytes).to_string(),
+            ValueType::U64 => le_value(bytes).to_string(),
+            ValueType::Guid => format!("0x{:016X}", le_value(bytes)),
+        }
+    }
+}
+
+fn le_value(bytes: &[u8]) -> u64 {
+    let mut buf = [0u8; 8];
+    let n = bytes.len().min(8);
+    buf[..n].copy_from_slice(&bytes[..n]);
+    u64::from_le_bytes(buf)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    /// Equal to the value typed in.
+    Exact,
+    Changed,
+    Unchanged,
+    Increased,
+    Decreased,
+}
+
+impl Filter {
+    pub const ALL: &'static [Filter] = &[
+        Filter::Exact,
+        Filter::Changed,
+        Filter::Unchanged,
+        Filter::Increased,
+        Filter::Decreased,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Filter::Exact => "Exact value",
+            Filter::Changed => "Changed",
+            Filter::Unchanged => "Unchanged",
+            Filter::Increased => "Increased",
+            Filter::Decreased => "Decreased",
+        }
+    }
+
+    /// Whether the filter needs a value typed in.
+    pub fn needs_value(&self) -> bool {
+        *self == Filter::Exact
+    }
+
+    /// Increased/decreased only make sense for numbers.
+    pub fn applies_to(&self, value_type: ValueType) -> bool {
+        value_type != ValueType::Text || !matches!(self, Filter::Increased | Filter::Decreased)
+    }
+}
+
+pub struct Hit {
+    pub address: usize,
+    /// Value at the last scan.
+    pub value: Vec<u8>,
+    /// Value at the scan before that (empty after the first scan).
+    pub previous: Vec<u8>,
+}
+
+pub struct MemoryScanner {
+    pub value_type: ValueType,
+    pub hits: Vec<Hit>,
+    /// Completed scans since the last first scan (0 = none yet).
+    pub scan_count: u32,
+}
+
+impl Default for MemoryScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryScanner {
+    pub fn new() -> Self {
+        Self {
+            value_type: ValueType::U32,
+            hits: Vec::new(),
+            scan_count: 0,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.hits.clear();
+        self.scan_count = 0;
+    }
+
+    /// Search all readable memory for `input`. Numbers are matched at 4-byte aligned
+    /// addresses only, as the client stores them.
+    pub fn first_scan(
+        &mut self,
+        reader: &SharedReader,
+        value_type: ValueType,
+        input: &str,
+    ) -> Result<usize, String> {
+        let needle = value_type.parse(input)?;
+        // Aligned before the cap, so unaligned matches cannot use up `MAX_HITS`.
+        let aligned = |addr: usize| value_type == ValueType::Text || addr.is_multiple_of(4);
+        let addresses = polling::scan_shared(reader, &needle, MAX_HITS, aligned)
+            .map_err(|e| format!("Scan error: {}", e))?;
+        self.value_type = value_type;
+        self.hits = addresses
+            .into_iter()
+            .map(|address| Hit {
+                address,
+                value: needle.clone(),
+                previous: Vec::new(),
+            })
+            .collect();
+        self.scan_count = 1;
+        info!(
+            "Scanner: first scan for {} '{}' found {} hits",
+            value_type.label(),
+            input,
+            self.hits.len()
+        );
+        Ok(self.hits.len())
+    }
+
+    /// Re-read every hit and keep those passing `filter` (`input` is used by `Exact`).
+    pub fn next_scan(
+        &mut self,
+        reader: &SharedReader,
+        filter: Filter,
+        input: &str,
+    ) -> Result<usize, String> {
+        let wanted = if filter.needs_value() {
+            Some(self.value_type.parse(input)?)
+        } else {
+            None
+        };
+        // Nothing readable at all (e.g. the memory was freed): every hit is dropped.
+        let current = self.read_hits(reader).unwrap_or_else(|e| {
+            warn!("Scanner: no hit readable: {}", e);
+            Vec::new()
+        });
+
+        let numeric = self.value_type != ValueType::Text;
+        let before = self.hits.len();
+        let mut kept = Vec::new();
+        let hits = std::mem::take(&mut self.hits);
+        let current = current.into_iter().chain(std::iter::repeat(Vec::new()));
+        for (mut hit, now) in hits.into_iter().zip(current) {
+            // The hit became unreadable (freed), or a text hit was only partly read.
+            if now.len() < hit.value.len() || now.is_empty() {
+                continue;
+            }
+            let keep = match filter {
+                Filter::Exact => wanted.as_deref() == Some(&now[..]),
+                Filter::Changed => now != hit.value,
+                Filter::Unchanged => now == hit.value,
+                Filter::Increased => numeric && le_value(&now) > le_value(&hit.value),
+                Filter::Decreased => numeric && le_value(&now) < le_value(&hit.value),
+            };
+            if keep {
+                hit.previous = std::mem::replace(&mut hit.value, now);
+                kept.push(hit);
+            }
+        }
+        self.hits = kept;
+        self.scan_count += 1;
+        info!(
+            "Scanner: next scan ({}) kept {} of {} hits",
+            filter.label(),
+            self.hits.len(),
+            before
+        );
+        Ok(self.hits.len())
+    }
+
+    /// Refresh the shown values without filtering. Unreadable hits show "??".
+    pub fn refresh(&mut self, reader: &SharedReader) -> io::Result<()> {
+        let current = self.read_hits(reader)?;
+        for (hit, now) in self.hits.iter_mut().zip(current) {
+            if now != hit.value {
+                hit.previous = std::mem::replace(&mut hit.value, now);
+            }
+        }
+        Ok(())
+    }
+
+    fn read_hits(&self, reader: &SharedReader) -> io::Result<Vec<Vec<u8>>> {
+        let mut values = Vec::with_capacity(self.hits.len());
+        let mut last_error = None;
+        for batch in self.hits.chunks(READ_BATCH) {
+            let ranges: Vec<(usize, usize)> = batch
+                .iter()
+                .map(|hit| (hit.address, hit.value.len().max(1)))
+                .collect();
+            match polling::lock_reader(reader).read_memory_ranges(&ranges) {
+                Ok(batch_values) => values.extend(batch_values),
+                Err(e) => {
+                    values.extend(std::iter::repeat_n(Vec::new(), batch.len()));
+                    last_error = Some(e);
+                }
+            }
+        }
+        // Like `read_memory_ranges`: an error only if nothing at all could be read.
+        match last_error {
+            Some(e) if values.iter().all(|v| v.is_empty()) => Err(e),
+            _ => Ok(values),
+        }
+    }
+}
+
+// ─── Background scans ────────────────────────────────────────────────
+
+/// A scan running on its own thread with its state (the memory scanner, or the
+/// discovery wizard's candidates) moved into it, so the UI stays responsive while
+/// polling keeps its turns at the reader lock.
+pub struct RunningScan<T = MemoryScanner> {
+    rx: mpsc::Receiver<(T, String)>,
+}
+
+impl<T: Default + Send + 'static> RunningScan<T> {
+    /// Run `scan` on `state` in the background; it returns the status line to show.
+    pub fn start<F>(mut state: T, scan: F) -> Self
+    where
+        F: FnOnce(&mut T) -> String + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let status = scan(&mut state);
+            let _ = tx.send((state, status));
+        });
+        Self { rx }
+    }
+
+    /// The state and its status line once the scan is done.
+    pub fn finished(&self) -> Option<(T, String)> {
+        match self.rx.try_recv() {
+            Ok(done) => Some(done),
+            Err(mpsc::TryRecvError::Empty) => None,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                Some((T::default(), "Scan failed (see log)".into()))
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+
+use crate::memory::ProcessMemoryReader;
+use crate::offsets::Offsets;
+use crate::player::PlayerInfo;
+use crate::polling::{self, PollEvent, PollingService, SharedReader};
+use crate::profiles::ClientVersion;
+
+// ─── Client session ──────────────────────────────────────────────────
+
+/// One attached game client (or loaded snapshot/replay) with its own reader and
+/// polling thread, so several clients can be read at once.
+pub struct ClientSession {
+    pub pid: u32,
+    /// Last character seen logged in on this client; kept across logouts.
+    pub character: String,
+    pub reader: SharedReader,
+    pub polling: PollingService,
+    poll_rx: mpsc::Receiver<(u64, PollEvent)>,
+    pub offsets: Offsets,
+    pub active_profile: Option<usize>,
+    pub client_version: Option<ClientVersion>,
+    pub player_info: Option<PlayerInfo>,
+    pub recording: bool,
+}
+
+impl ClientSession {
+    /// Wrap an already attached `reader` and start polling it with `offsets`.
+    pub fn start(
+        pid: u32,
+        reader: Box<dyn ProcessMemoryReader + Send>,
+        offsets: Offsets,
+        interval_ms: u64,
+    ) -> Self {
+        let reader: SharedReader = Arc::new(Mutex::new(reader));
+        let (mut polling, poll_rx) = PollingService::start_thread(reader.clone(), interval_ms);
+        polling.start(offsets);
+        Self {
+            pid,
+            character: String::new(),
+            reader,
+            polling,
+            poll_rx,
+            offsets,
+            active_profile: None,
+            client_version: None,
+            player_info: None,
+            recording: false,
+        }
+    }
+
+    /// Character name, or the PID until a character has been seen.
+    pub fn label(&self) -> String {
+        if self.character.is_empty() {
+            format!("PID {}", self.pid)
+        } else {
+            self.character.clone()
+        }
+    }
+
+    /// Next pending event from the polling thread, skipping any left over from before
+    /// the last restart or stop.
+    pub fn next_event(&self) -> Option<PollEvent> {
+        while let Ok((generation, event)) = self.poll_rx.try_recv() {
+            if generation == self.polling.generation() {
+                return Some(event);
+            }
+        }
+        None
+    }
+
+    pub fn set_player_info(&mut self, info: Option<PlayerInfo>) {
+        if let Some(name) = info.as_ref().map(|i| &i.name).filter(|n| !n.is_empty()) {
+            self.character.clone_from(name);
+        }
+        self.player_info = info;
+    }
+
+    /// Stop polling and release the process.
+    pub fn detach(&mut self) {
+        self.polling.stop();
+        let _ = polling::lock_reader(&self.reader).detach();
+    }
+}
@@ -0,0 +1,383 @@
+use log::{debug, info, warn};
+use std::collections::BTreeMap;
+use std::io;
+
+use crate::chat::{self, TOTAL_BUFFER_SIZE};
+use crate::memory::ProcessMemoryReader;
+use crate::offsets::{self, Offsets};
+
+// ─── Byte patterns ───────────────────────────────────────────────────
+
+/// Wow.exe 3.3.5a has a fixed image base (0x00400000) and no ASLR;
+/// signatures are only searched in its code section.
+const CODE_START: usize = 0x0040_1000;
+const CODE_END: usize = 0x00A0_0000;
+
+/// A byte pattern with wildcards, written IDA-style: `"A1 ?? ?? ?? ?? 40"`.
+pub struct Pattern {
+    bytes: Vec<Option<u8>>,
+}
+
+impl Pattern {
+    pub fn parse(s: &str) -> Option<Self> {
+        let bytes = s
+            .split_whitespace()
+            .map(|tok| match tok {
+                "?" | "??" => Some(None),
+                hex => u8::from_str_radix(hex, 16).ok().map(Some),
+            })
+            .collect::<Option<Vec<_>>>()?;
+        if bytes.is_empty() {
+            return None;
+        }
+        Some(Self { bytes })
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Longest run of fixed bytes, as (offset into pattern, bytes).
+    /// Used as the exact needle for `scan_for_bytes`.
+    fn anchor(&self) -> (usize, Vec<u8>) {
+        let mut best = (0, Vec::new());
+        let mut run_start = 0;
+        let mut run = Vec::new();
+        for (i, b) in self.bytes.iter().enumerate() {
+            match b {
+                Some(b) => {
+                    if run.is_empty() {
+                        run_start = i;
+                    }
+                    run.push(*b);
+                    if run.len() > best.1.len() {
+                        best = (run_start, run.clone());
+                    }
+                }
+                None => run.clear(),
+            }
+        }
+        best
+    }
+
+    fn matches(&self, data: &[u8]) -> bool {
+        data.len() >= self.bytes.len()
+            && self
+                .bytes
+                .iter()
+                .zip(data)
+                .all(|(p, d)| p.is_none_or(|p| p == *d))
+    }
+}
+
+/// Find all addresses in `[start, end)` where `pattern` matches.
+///
+/// Scans for the pattern's longest fixed run with `scan_range_for_bytes`,
+/// then reads around each hit to check the wildcard bytes.
+pub fn find_pattern(
+    reader: &dyn ProcessMemoryReader,
+    pattern: &Pattern,
+    start: usize,
+    end: usize,
+) -> io::Result<Vec<usize>> {
+    let (anchor_offset, anchor) = pattern.anchor();
+    if anchor.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "pattern has no fixed bytes",
+        ));
+    }
+
+    let mut matches = Vec::new();
+    for hit in reader.scan_range_for_bytes(&anchor, start, end)? {
+        let Some(base) = hit.checked_sub(anchor_offset) else {
+            continue;
+        };
+        match reader.read_memory(base, pattern.len()) {
+            Ok(data) if pattern.matches(&data) => matches.push(base),
+            _ => {}
+        }
+    }
+    Ok(matches)
+}
+
+// ─── Chat offset signatures ─────────────────────────────────────────
+
+/// Code pattern that embeds an absolute address as a 32-bit immediate/displacement.
+struct Signature {
+    pattern: &'static str,
+    /// Byte offset of the address within the pattern.
+    capture: usize,
+}
+
+/// Slot address computation: `imul r32, r/m32, 0x17C0` followed by an
+/// instruction carrying the buffer base (`lea r32, [r32+base]` / `add r32, base`).
+const CHAT_BUFFER_START_SIGNATURES: &[Signature] = &[
+    Signature {
+        pattern: "69 ?? C0 17 00 00 8D ?? ?? ?? ?? ??",
+        capture: 8,
+    },
+    Signature {
+        pattern: "69 ?? C0 17 00 00 81 ?? ?? ?? ?? ??",
+        capture: 8,
+    },
+];
+
+/// Next-write index update: load the counter, increment, then `% 60` via `idiv`.
+const CHAT_BUFFER_COUNT_SIGNATURES: &[Signature] = &[
+    Signature {
+        pattern: "A1 ?? ?? ?? ?? 40 99 B9 3C 00 00 00 F7 F9",
+        capture: 1,
+    },
+    Signature {
+        pattern: "8B 0D ?? ?? ?? ?? 41 8B C1 99 BE 3C 00 00 00 F7 FE",
+        capture: 2,
+    },
+];
+
+/// Player name getter: `cmp byte ptr [name], 0; jz ...; mov eax, offset name`. The
+/// shape is common, so a hit only counts while logged in and when it is the only one.
+const PLAYER_NAME_SIGNATURES: &[Signature] = &[Signature {
+    pattern: "80 3D ?? ?? ?? ?? 00 74 ?? B8 ?? ?? ?? ??",
+    capture: 2,
+}];
+
+/// Checks that a candidate address points at plausible data.
+type Validator<'a> = &'a dyn Fn(usize) -> bool;
+
+pub struct SignatureScan {
+    pub offsets: Offsets,
+    pub matched: usize,
+    pub total: usize,
+}
+
+/// Locate the chat buffer start, next-write index and player name addresses from code
+/// signatures. Each address keeps its value from `base` (the active offset profile)
+/// if no signature yields a value that passes validation. The count is validated
+/// against the buffer resolved first, so it has to agree with the slots' sequences.
+/// Fields listed in `pinned` (saved by hand into the profile) are not scanned.
+pub fn resolve_offsets(
+    reader: &dyn ProcessMemoryReader,
+    base: &Offsets,
+    pinned: &[String],
+) -> SignatureScan {
+    info!("Resolving chat offsets from signatures...");
+    let mut offsets = *base;
+    let mut matched = 0;
+    let is_pinned = |field: &str| {
+        let pinned = pinned.iter().any(|p| p == field);
+        if pinned {
+            info!("  {}: pinned in the profile, not scanned", field);
+        }
+        pinned
+    };
+    let mut total = 0;
+
+    if !is_pinned("chat_buffer_start") {
+        total += 1;
+        let found = resolve_one(
+            reader,
+            "chat_buffer_start",
+            CHAT_BUFFER_START_SIGNATURES,
+            &|addr| is_valid_chat_buffer(reader, addr),
+        );
+        if found.is_none() && !is_valid_chat_buffer(reader, offsets.chat_buffer_start) {
+            // Sources disagree on whether the buffer starts at 0x00B75A60 or 8 bytes
+            // earlier (docs/offsets.md §1.1); keep whichever one holds chat messages.
+            let alternatives = [offsets::CHAT_BUFFER_START, offsets::CHAT_BUFFER_START_ALT];
+            if let Some(&addr) = alternatives.iter().find(|&&a| is_valid_chat_buffer(reader, a))
+            {
+                info!(
+                    "  chat_buffer_start: profile 0x{:X} holds no messages, using 0x{:X}",
+                    offsets.chat_buffer_start, addr
+                );
+                offsets.chat_buffer_start = addr;
+            }
+        }
+        apply(found, "chat_buffer_start", &mut offsets.chat_buffer_start, &mut matched);
+    }
+
+    if !is_pinned("chat_buffer_count") {
+        total += 1;
+        let buffer_start = offsets.chat_buffer_start;
+        let found = resolve_one(
+            reader,
+            "chat_buffer_count",
+            CHAT_BUFFER_COUNT_SIGNATURES,
+            &|addr| is_valid_chat_count(reader, addr, buffer_start),
+        );
+        apply(found, "chat_buffer_count", &mut offsets.chat_buffer_count, &mut matched);
+    }
+
+    if !is_pinned("player_name") {
+        total += 1;
+        let realm_name = offsets.realm_name;
+        let votes = collect_votes(
+            reader,
+            "player_name",
+            PLAYER_NAME_SIGNATURES,
+            &|addr| is_valid_player_name(reader, addr, realm_name),
+        );
+        let found = match votes.len() {
+            1 => votes.into_keys().next(),
+            0 => None,
+            _ => {
+                warn!("  player_name: several candidates {:X?}, ignoring them", votes);
+                None
+            }
+        };
+        apply(found, "player_name", &mut offsets.player_name, &mut matched);
+    }
+
+    SignatureScan {
+        offsets,
+        matched,
+        total,
+    }
+}
+
+/// Store a resolved address in `slot`, logging how it compares to the profile value.
+fn apply(found: Option<usize>, label: &str, slot: &mut usize, matched: &mut usize) {
+    match found {
+        Some(addr) => {
+            if addr != *slot {
+                info!("  {}: 0x{:X} (profile 0x{:X})", label, addr, *slot);
+            } else {
+                info!("  {}: 0x{:X} (matches profile)", label, addr);
+            }
+            *slot = addr;
+            *matched += 1;
+        }
+        None => info!("  {}: no signature match, using profile 0x{:X}", label, *slot),
+    }
+}
+
+/// Collect candidate addresses from every signature hit and pick the most common valid one.
+fn resolve_one(
+    reader: &dyn ProcessMemoryReader,
+    label: &str,
+    signatures: &[Signature],
+    is_valid: Validator,
+) -> Option<usize> {
+    let votes = collect_votes(reader, label, signatures, is_valid);
+    if votes.len() > 1 {
+        warn!("  {}: conflicting candidates {:X?}", label, votes);
+    }
+    votes
+        .into_iter()
+        .max_by_key(|&(_, count)| count)
+        .map(|(addr, _)| addr)
+}
+
+/// Valid candidate addresses from every signature hit, with how many hits gave each.
+fn collect_votes(
+    reader: &dyn ProcessMemoryReader,
+    label: &str,
+    signatures: &[Signature],
+    is_valid: Validator,
+) -> BTreeMap<usize, u32> {
+    let mut votes: BTreeMap<usize, u32> = BTreeMap::new();
+
+    for sig in signatures {
+        let Some(pattern) = Pattern::parse(sig.pattern) else {
+            warn!("  {}: invalid pattern \"{}\"", label, sig.pattern);
+            continue;
+        };
+        let hits = match find_pattern(reader, &pattern, CODE_START, CODE_END) {
+            Ok(hits) => hits,
+            Err(e) => {
+                debug!("  {}: scan failed: {}", label, e);
+                continue;
+            }
+        };
+        debug!("  {}: \"{}\" — {} hits", label, sig.pattern, hits.len());
+
+        for hit in hits {
+            let Ok(data) = reader.read_memory(hit + sig.capture, 4) else {
+                continue;
+            };
+            let Some(bytes) = data.get(..4) else {
+                continue;
+            };
+            let addr = u32::from_le_bytes(bytes.try_into().unwrap()) as usize;
+            if is_valid(addr) {
+                *votes.entry(addr).or_default() += 1;
+            } else {
+                debug!("  {}: candidate 0x{:X} failed validation", label, addr);
+            }
+        }
+    }
+    votes
+}
+
+// ─── Candidate validation ───────────────────────────────────────────
+
+/// The buffer must be readable and hold at least one slot that looks like a message.
+fn is_valid_chat_buffer(reader: &dyn ProcessMemoryReader, addr: usize) -> bool {
+    let Ok(data) = reader.read_memory(addr, TOTAL_BUFFER_SIZE) else {
+        return false;
+    };
+    data.len() == TOTAL_BUFFER_SIZE
+        && data
+            .chunks_exact(offsets::CHAT_MESSAGE_STRIDE)
+            .any(chat::is_plausible_slot)
+}
+
+/// The next-write index must be < 60 and point just past the newest message: the slot
+/// before it holds the highest sequence number, or, on builds that leave sequences at
+/// zero, a message at all. Rejects zeroed globals that merely happen to be < 60.
+fn is_valid_chat_count(
+    reader: &dyn ProcessMemoryReader,
+    addr: usize,
+    buffer_start: usize,
+) -> bool {
+    let Some(index) = reader
+        .read_memory(addr, 4)
+        .ok()
+        .and_then(|data| data.get(..4).map(|b| u32::from_le_bytes(b.try_into().unwrap())))
+        .map(|index| index as usize)
+        .filter(|&index| index < offsets::CHAT_BUFFER_SIZE)
+    else {
+        return false;
+    };
+    let Ok(data) = reader.read_memory(buffer_start, TOTAL_BUFFER_SIZE) else {
+        return false;
+    };
+    if data.len() < TOTAL_BUFFER_SIZE {
+        return false;
+    }
+    let slots: Vec<&[u8]> = data.chunks_exact(offsets::CHAT_MESSAGE_STRIDE).collect();
+    let sequence = |slot: &[u8]| {
+        let word = &slot[offsets::MSG_SEQUENCE..offsets::MSG_SEQUENCE + 4];
+        u32::from_le_bytes(word.try_into().unwrap())
+    };
+    let newest = (index + offsets::CHAT_BUFFER_SIZE - 1) % offsets::CHAT_BUFFER_SIZE;
+    let max_sequence = slots.iter().map(|slot| sequence(slot)).max().unwrap_or(0);
+    if max_sequence > 0 {
+        sequence(slots[newest]) == max_sequence
+    } else {
+        chat::is_plausible_slot(slots[newest])
+    }
+}
+
+/// Longest character name the client allows, in characters.
+const MAX_CHARACTER_NAME_CHARS: usize = 12;
+
+/// A logged-in character's name: 1-12 characters of UTF-8 without control bytes, with
+/// a realm name at the profile's `realm_name`. Zeroed globals and the login screen
+/// fail, so the profile value is kept.
+fn is_valid_player_name(reader: &dyn ProcessMemoryReader, addr: usize, realm_addr: usize) -> bool {
+    let is_name = |addr: usize, max_chars: usize| {
+        let Ok(data) = reader.read_memory(addr, 49) else {
+            return false;
+        };
+        let Some(end) = data.iter().position(|&b| b == 0) else {
+            return false;
+        };
+        std::str::from_utf8(&data[..end]).is_ok_and(|name| {
+            let chars = name.chars().count();
+            (1..=max_chars).contains(&chars) && !name.chars().any(char::is_control)
+        })
+    };
+    is_name(addr, MAX_CHARACTER_NAME_CHARS) && is_name(realm_addr, 48)
+}
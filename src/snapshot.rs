@@ -0,0 +1,315 @@
+use log::{info, warn};
+use std::cell::RefCell;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use crate::chat::TOTAL_BUFFER_SIZE;
+use crate::memory::ProcessMemoryReader;
+use crate::offsets::Offsets;
+use crate::player;
+
+// ─── File format ─────────────────────────────────────────────────────
+//
+// All integers little-endian:
+//   magic    b"WCTSNAP2"   (version 1 had no offsets block)
+//   pid      u32          (PID of the process the snapshot was taken from)
+//   offsets  u32 len + JSON-encoded `Offsets` in use when the snapshot was taken
+//   count    u32          (number of regions)
+//   count × { address u64, len u32, bytes[len] }
+
+const SNAPSHOT_MAGIC: &[u8; 8] = b"WCTSNAP2";
+pub const SNAPSHOT_EXTENSION: &str = "wsnap";
+
+struct SnapshotRegion {
+    address: usize,
+    data: Vec<u8>,
+}
+
+impl SnapshotRegion {
+    /// One past the last byte; `load` rejects regions that would run past `usize::MAX`.
+    fn end(&self) -> usize {
+        self.address.saturating_add(self.data.len())
+    }
+}
+
+// ─── Snapshot reader ─────────────────────────────────────────────────
+
+/// Serves `read_memory` from address ranges saved to a file instead of a live process.
+pub struct SnapshotMemoryReader {
+    pid: u32,
+    offsets: Offsets,
+    /// Sorted by address, non-overlapping.
+    regions: Vec<SnapshotRegion>,
+    attached: bool,
+}
+
+impl SnapshotMemoryReader {
+    /// Build a snapshot from raw `(address, bytes)` reads, merging overlapping ranges.
+    fn from_reads(pid: u32, offsets: Offsets, mut reads: Vec<(usize, Vec<u8>)>) -> Self {
+        reads.retain(|(_, data)| !data.is_empty());
+        reads.sort_by_key(|(address, _)| *address);
+
+        let mut regions: Vec<SnapshotRegion> = Vec::new();
+        for (address, data) in reads {
+            match regions.last_mut() {
+                Some(last) if address <= last.end() => {
+                    // Overlapping or adjacent — extend the previous region.
+                    let overlap = last.end() - address;
+                    if overlap < data.len() {
+                        last.data.extend_from_slice(&data[overlap..]);
+                    }
+                }
+                _ => regions.push(SnapshotRegion { address, data }),
+            }
+        }
+
+        Self {
+            pid,
+            offsets,
+            regions,
+            attached: true,
+        }
+    }
+
+    /// Capture the chat buffer, player name/realm and object manager chain from `reader`.
+    ///
+    /// The object manager walk is recorded by running `player::read_player_info`
+    /// against the live reader, so the snapshot holds exactly the addresses it visits.
+    pub fn capture(reader: &dyn ProcessMemoryReader, pid: u32, offsets: &Offsets) -> Self {
+        let recorder = RecordingReader {
+            inner: reader,
+            reads: RefCell::new(Vec::new()),
+        };
+
+        for (label, addr, size) in [
+            ("chat buffer", offsets.chat_buffer_start, TOTAL_BUFFER_SIZE),
+            ("CHAT_BUFFER_COUNT", offsets.chat_buffer_count, 4),
+            ("CHAT_BUFFER_COUNT_ALT", offsets.chat_buffer_count_alt, 4),
+            ("PLAYER_GUID", offsets.player_guid, 8),
+        ] {
+            if let Err(e) = recorder.read_memory(addr, size) {
+                warn!("Snapshot: {} @ 0x{:X} unreadable: {}", label, addr, e);
+            }
+        }
+        let _ = player::read_player_info(&recorder, offsets);
+
+        let snapshot = Self::from_reads(pid, *offsets, recorder.reads.into_inner());
+        info!(
+            "Captured snapshot of PID={}: {} regions, {} bytes",
+            pid,
+            snapshot.regions.len(),
+            snapshot.total_bytes(),
+        );
+        snapshot
+    }
+
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Offsets the snapshot was captured with; reads must use these to hit saved ranges.
+    pub fn offsets(&self) -> Offsets {
+        self.offsets
+    }
+
+    pub fn total_bytes(&self) -> usize {
+        self.regions.iter().map(|r| r.data.len()).sum()
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut out = Vec::with_capacity(16 + self.total_bytes() + self.regions.len() * 12);
+        out.extend_from_slice(SNAPSHOT_MAGIC);
+        out.extend_from_slice(&self.pid.to_le_bytes());
+        write_offsets(&mut out, &self.offsets)?;
+        out.extend_from_slice(&(self.regions.len() as u32).to_le_bytes());
+        for region in &self.regions {
+            out.extend_from_slice(&(region.address as u64).to_le_bytes());
+            out.extend_from_slice(&(region.data.len() as u32).to_le_bytes());
+            out.extend_from_slice(&region.data);
+        }
+        std::fs::File::create(path)?.write_all(&out)?;
+        info!("Saved snapshot to {}", path.display());
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let bytes = std::fs::read(path)?;
+        let mut file = io::Cursor::new(bytes.as_slice());
+
+        check_magic(&mut file, SNAPSHOT_MAGIC, "snapshot")?;
+
+        let pid = read_u32_le(&mut file)?;
+        let offsets = read_offsets(&mut file)?;
+        // Each region takes at least its 12-byte header.
+        let count = read_len(&mut file, 12)?;
+        let mut reads = Vec::with_capacity(count);
+        for _ in 0..count {
+            let mut addr = [0u8; 8];
+            file.read_exact(&mut addr)?;
+            let len = read_len(&mut file, 1)?;
+            let address = usize::try_from(u64::from_le_bytes(addr))
+                .ok()
+                .filter(|a| a.checked_add(len).is_some())
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "snapshot region 0x{:X} (+{} bytes) is outside the address space",
+                            u64::from_le_bytes(addr),
+                            len
+                        ),
+                    )
+                })?;
+            let mut data = vec![0u8; len];
+            file.read_exact(&mut data)?;
+            reads.push((address, data));
+        }
+
+        let snapshot = Self::from_reads(pid, offsets, reads);
+        info!(
+            "Loaded snapshot {} (PID={}, {} regions, {} bytes)",
+            path.display(),
+            pid,
+            snapshot.regions.len(),
+            snapshot.total_bytes(),
+        );
+        Ok(snapshot)
+    }
+}
+
+/// Read the 8-byte magic: a name followed by a format version, e.g. `WCTSNAP2`. A
+/// known name with another version gets its own error instead of a parse failure
+/// further in.
+pub(crate) fn check_magic(r: &mut impl Read, expected: &[u8; 8], what: &str) -> io::Result<()> {
+    let mut magic = [0u8; 8];
+    r.read_exact(&mut magic)?;
+    if &magic == expected {
+        return Ok(());
+    }
+    let name_len = expected.iter().rposition(|b| !b.is_ascii_digit()).map_or(0, |i| i + 1);
+    let message = if magic[..name_len] == expected[..name_len] {
+        format!(
+            "{} format version {} is not supported (expected version {})",
+            what,
+            String::from_utf8_lossy(&magic[name_len..]),
+            String::from_utf8_lossy(&expected[name_len..]),
+        )
+    } else {
+        format!("not a chat translator {} file", what)
+    };
+    Err(io::Error::new(io::ErrorKind::InvalidData, message))
+}
+
+pub(crate) fn read_u32_le(r: &mut impl Read) -> io::Result<u32> {
+    let mut buf = [0u8; 4];
+    r.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+/// Fail with `InvalidData` unless `count` items of `item_size` bytes fit in the rest of
+/// the file. Counts and lengths come from the file itself, so a corrupt or hostile file
+/// must not be able to force a huge allocation.
+pub(crate) fn check_fits(r: &io::Cursor<&[u8]>, count: usize, item_size: usize) -> io::Result<()> {
+    let remaining = r.get_ref().len().saturating_sub(r.position() as usize);
+    if count.saturating_mul(item_size) > remaining {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{} × {} bytes exceeds the {} bytes left in the file",
+                count, item_size, remaining
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Read a u32 count of `item_size`-byte items, checked with `check_fits`.
+pub(crate) fn read_len(r: &mut io::Cursor<&[u8]>, item_size: usize) -> io::Result<usize> {
+    let len = read_u32_le(r)? as usize;
+    check_fits(r, len, item_size)?;
+    Ok(len)
+}
+
+/// Read a length-prefixed JSON `Offsets` block (shared with recordings).
+pub(crate) fn read_offsets(r: &mut io::Cursor<&[u8]>) -> io::Result<Offsets> {
+    let len = read_len(r, 1)?;
+    let mut json = vec![0u8; len];
+    r.read_exact(&mut json)?;
+    serde_json::from_slice(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Write a length-prefixed JSON `Offsets` block.
+pub(crate) fn write_offsets(w: &mut impl Write, offsets: &Offsets) -> io::Result<()> {
+    let json = serde_json::to_vec(offsets).map_err(io::Error::other)?;
+    w.write_all(&(json.len() as u32).to_le_bytes())?;
+    w.write_all(&json)
+}
+
+impl ProcessMemoryReader for SnapshotMemoryReader {
+    fn attach(&mut self, _pid: u32) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Snapshot reader cannot attach to a live process",
+        ))
+    }
+
+    fn read_memory(&self, address: usize, size: usize) -> io::Result<Vec<u8>> {
+        if !self.attached {
+            return Err(io::Error::new(io::ErrorKind::NotConnected, "Not attached"));
+        }
+        // Last region starting at or before `address`.
+        let idx = self.regions.partition_point(|r| r.address <= address);
+        match idx.checked_sub(1).map(|i| &self.regions[i]) {
+            Some(region) if address < region.end() => {
+                // Short read past the region end, like a partial ReadProcessMemory.
+                let start = address - region.address;
+                let end = start.saturating_add(size).min(region.data.len());
+                Ok(region.data[start..end].to_vec())
+            }
+            _ => Err(io::Error::other(format!(
+                "Address 0x{:X} not in snapshot",
+                address
+            ))),
+        }
+    }
+
+    fn detach(&mut self) -> io::Result<()> {
+        self.attached = false;
+        Ok(())
+    }
+
+    fn is_attached(&self) -> bool {
+        self.attached
+    }
+}
+
+// ─── Recording wrapper ───────────────────────────────────────────────
+
+/// Forwards reads to a live reader and keeps a copy of every successful result.
+struct RecordingReader<'a> {
+    inner: &'a dyn ProcessMemoryReader,
+    reads: RefCell<Vec<(usize, Vec<u8>)>>,
+}
+
+impl ProcessMemoryReader for RecordingReader<'_> {
+    fn attach(&mut self, _pid: u32) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Recording reader cannot attach",
+        ))
+    }
+
+    fn read_memory(&self, address: usize, size: usize) -> io::Result<Vec<u8>> {
+        let data = self.inner.read_memory(address, size)?;
+        self.reads.borrow_mut().push((address, data.clone()));
+        Ok(data)
+    }
+
+    fn detach(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn is_attached(&self) -> bool {
+        self.inner.is_attached()
+    }
+}
@@ -49,7 +49,8 @@ pub fn prepare_for_translation(segments: &[TextSegment]) -> (String, Vec<String>
     for seg in segments {
         match seg {
             TextSegment::Plain(s) => text.push_str(s),
-            TextSegment::WowLink { display_name, .. } => {
+            TextSegment::WowLink { display_name, .. }
+            | TextSegment::Player { display_name, .. } => {
                 link_names.push(display_name.clone());
                 // Fullwidth angle brackets — DeepL treats these as non-translatable tokens
                 text.push_str(&format!("\u{3008}{}\u{3009}", link_names.len()));
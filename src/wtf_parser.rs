@@ -8,6 +8,7 @@ use crate::chat::{ChatMessageType, ChatTab};
 // ─── Types ───────────────────────────────────────────────────────────
 
 pub struct CharacterConfig {
+    #[allow(dead_code)]
     pub account: String,
     pub realm: String,
     pub character: String,
@@ -128,8 +129,8 @@ pub fn parse_chat_cache(path: &Path) -> io::Result<Vec<WtfChatWindow>> {
                 // Ignore VERSION, ADDEDVERSION, etc.
             }
             State::InWindow => {
-                if line.starts_with("NAME ") {
-                    current_name = line["NAME ".len()..].to_string();
+                if let Some(name) = line.strip_prefix("NAME ") {
+                    current_name = name.to_string();
                 } else if line == "MESSAGES" {
                     state = State::InMessages;
                 } else if line == "CHANNELS" || line == "ZONECHANNELS" {
@@ -150,12 +151,12 @@ pub fn parse_chat_cache(path: &Path) -> io::Result<Vec<WtfChatWindow>> {
             State::InMessages => {
                 if line == "END" {
                     state = State::InWindow;
-                } else if let Some(msg_type) = wtf_type_to_chat_message_type(line) {
-                    if !current_types.contains(&msg_type) {
-                        current_types.push(msg_type);
-                    }
                 } else {
-                    debug!("Unknown WTF message type: {}", line);
+                    for &msg_type in wtf_group_to_chat_message_types(line) {
+                        if !current_types.contains(&msg_type) {
+                            current_types.push(msg_type);
+                        }
+                    }
                 }
             }
             State::SkipSection => {
@@ -196,58 +197,71 @@ pub fn parse_chat_cache(path: &Path) -> io::Result<Vec<WtfChatWindow>> {
 
 // ─── WTF type name → ChatMessageType mapping ────────────────────────
 
-fn wtf_type_to_chat_message_type(name: &str) -> Option<ChatMessageType> {
+/// Message types of a `MESSAGES` entry. chat-cache.txt stores the client's
+/// ChatTypeGroup names, and a group can cover several types (PARTY also shows party
+/// leader and monster party messages). Empty for groups that never reach the
+/// 3.3.5a chat buffer and for unknown names.
+fn wtf_group_to_chat_message_types(name: &str) -> &'static [ChatMessageType] {
+    use ChatMessageType::*;
     match name {
-        "SAY" => Some(ChatMessageType::Say),
-        "PARTY" | "PARTY_LEADER" => Some(ChatMessageType::Party),
-        "RAID" | "RAID_LEADER" | "RAID_WARNING" => Some(ChatMessageType::Raid),
-        "GUILD" => Some(ChatMessageType::Guild),
-        "OFFICER" => Some(ChatMessageType::Officer),
-        "YELL" => Some(ChatMessageType::Yell),
-        "WHISPER" => Some(ChatMessageType::Whisper),
-        "WHISPER_FOREIGN" => Some(ChatMessageType::WhisperMob),
-        "WHISPER_INFORM" => Some(ChatMessageType::WhisperInform),
-        "EMOTE" => Some(ChatMessageType::Emote),
-        "TEXT_EMOTE" => Some(ChatMessageType::TextEmote),
-        "MONSTER_SAY" => Some(ChatMessageType::MonsterSay),
-        "MONSTER_PARTY" => Some(ChatMessageType::MonsterParty),
-        "MONSTER_YELL" => Some(ChatMessageType::MonsterYell),
-        "MONSTER_WHISPER" => Some(ChatMessageType::MonsterWhisper),
-        "MONSTER_EMOTE" => Some(ChatMessageType::MonsterEmote),
-        "CHANNEL" => Some(ChatMessageType::Channel),
-        "CHANNEL_JOIN" => Some(ChatMessageType::ChannelJoin),
-        "CHANNEL_LEAVE" => Some(ChatMessageType::ChannelLeave),
-        "CHANNEL_LIST" => Some(ChatMessageType::ChannelList),
-        "CHANNEL_NOTICE" => Some(ChatMessageType::ChannelNotice),
-        "CHANNEL_NOTICE_USER" => Some(ChatMessageType::ChannelNoticeUser),
-        "AFK" => Some(ChatMessageType::Afk),
-        "DND" => Some(ChatMessageType::Dnd),
-        "IGNORED" => Some(ChatMessageType::Ignored),
-        "SKILL" => Some(ChatMessageType::Skill),
-        "LOOT" => Some(ChatMessageType::Loot),
-        "SYSTEM" | "SYSTEM_NOMENU" | "ERRORS" => Some(ChatMessageType::System),
-        "BATTLEGROUND" | "BATTLEGROUND_LEADER" => Some(ChatMessageType::Raid),
-        "MONSTER_BOSS_EMOTE" => Some(ChatMessageType::MonsterEmote),
-        "MONSTER_BOSS_WHISPER" => Some(ChatMessageType::MonsterWhisper),
-        "BG_HORDE" | "BG_ALLIANCE" | "BG_NEUTRAL"
-        | "BG_SYSTEM_NEUTRAL" | "BG_SYSTEM_ALLIANCE" | "BG_SYSTEM_HORDE" => {
-            Some(ChatMessageType::System)
-        }
-        "MONEY" => Some(ChatMessageType::Loot),
-        "COMBAT_XP_GAIN" | "COMBAT_HONOR_GAIN" | "COMBAT_FACTION_CHANGE"
-        | "COMBAT_MISC_INFO" => Some(ChatMessageType::System),
-        "TRADESKILLS" | "OPENING" | "PET_INFO" => Some(ChatMessageType::Skill),
-        // Types present in WTF with no meaningful mapping — skip silently.
-        "ACHIEVEMENT" | "GUILD_ACHIEVEMENT" | "BN_WHISPER" | "BN_WHISPER_INFORM"
-        | "BN_CONVERSATION" | "BN_CONVERSATION_NOTICE" | "BN_CONVERSATION_LIST"
-        | "BN_INLINE_TOAST_ALERT" | "BN_INLINE_TOAST_BROADCAST"
+        "SYSTEM" | "SYSTEM_NOMENU" => &[System],
+        "SAY" => &[Say],
+        "EMOTE" => &[Emote, TextEmote],
+        "YELL" => &[Yell],
+        "WHISPER" => &[Whisper, WhisperInform, Afk, Dnd],
+        "PARTY" => &[Party, MonsterParty, PartyLeader],
+        "PARTY_LEADER" => &[PartyLeader],
+        "RAID" => &[Raid, RaidLeader, RaidWarning],
+        "RAID_LEADER" => &[RaidLeader],
+        "RAID_WARNING" => &[RaidWarning],
+        "BATTLEGROUND" => &[Battleground, BattlegroundLeader],
+        "BATTLEGROUND_LEADER" => &[BattlegroundLeader],
+        "GUILD" => &[Guild],
+        "OFFICER" => &[Officer],
+        "MONSTER_SAY" => &[MonsterSay],
+        "MONSTER_YELL" => &[MonsterYell],
+        "MONSTER_EMOTE" => &[MonsterEmote],
+        "MONSTER_WHISPER" => &[MonsterWhisper],
+        "MONSTER_BOSS_EMOTE" => &[RaidBossEmote],
+        "MONSTER_BOSS_WHISPER" => &[RaidBossWhisper],
+        "ERRORS" => &[Restricted, Filtered],
+        "AFK" => &[Afk],
+        "DND" => &[Dnd],
+        "IGNORED" => &[Ignored],
+        "BG_HORDE" => &[BgSystemHorde],
+        "BG_ALLIANCE" => &[BgSystemAlliance],
+        "BG_NEUTRAL" => &[BgSystemNeutral],
+        "COMBAT_XP_GAIN" => &[CombatXpGain],
+        "COMBAT_HONOR_GAIN" => &[CombatHonorGain],
+        "COMBAT_FACTION_CHANGE" => &[CombatFactionChange],
+        "SKILL" => &[Skill],
+        "LOOT" => &[Loot],
+        "MONEY" => &[Money],
+        "OPENING" => &[Opening],
+        "TRADESKILLS" => &[Tradeskills],
+        "PET_INFO" => &[PetInfo],
+        "COMBAT_MISC_INFO" => &[CombatMiscInfo],
+        "ACHIEVEMENT" => &[Achievement],
+        "GUILD_ACHIEVEMENT" => &[GuildAchievement],
+        // The client's group has only the join/leave notices; which channels a window
+        // shows is listed in its CHANNELS section, which is not parsed, so the channel
+        // messages come with the group.
+        "CHANNEL" => &[
+            Channel,
+            ChannelJoin,
+            ChannelLeave,
+            ChannelNotice,
+            ChannelNoticeUser,
+            ChannelList,
+        ],
+        // Groups present in WTF that never reach the 3.3.5a chat buffer — skip silently.
+        "BN_WHISPER" | "BN_WHISPER_INFORM" | "BN_CONVERSATION" | "BN_CONVERSATION_NOTICE"
+        | "BN_CONVERSATION_LIST" | "BN_INLINE_TOAST_ALERT" | "BN_INLINE_TOAST_BROADCAST"
         | "BN_INLINE_TOAST_BROADCAST_INFORM" | "BN_INLINE_TOAST_CONVERSATION"
-        | "TARGETICONS" | "FILTERED" | "RESTRICTED" | "ADDON"
-        | "RAID_BOSS_EMOTE" | "RAID_BOSS_WHISPER" | "BATTLENET"
-        | "ARENA_POINTS" => None,
+        | "TARGETICONS" => &[],
         _ => {
-            warn!("Unrecognized WTF message type: {}", name);
-            None
+            warn!("Unrecognized WTF message group: {}", name);
+            &[]
         }
     }
 }
@@ -273,3 +287,25 @@ pub fn to_chat_tabs(windows: &[WtfChatWindow]) -> Vec<ChatTab> {
 
     tabs
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_cover_their_types() {
+        use ChatMessageType::*;
+        assert_eq!(wtf_group_to_chat_message_types("PARTY"), [Party, MonsterParty, PartyLeader]);
+        assert_eq!(wtf_group_to_chat_message_types("RAID"), [Raid, RaidLeader, RaidWarning]);
+        assert_eq!(
+            wtf_group_to_chat_message_types("BATTLEGROUND"),
+            [Battleground, BattlegroundLeader]
+        );
+        assert_eq!(
+            wtf_group_to_chat_message_types("WHISPER"),
+            [Whisper, WhisperInform, Afk, Dnd]
+        );
+        assert_eq!(wtf_group_to_chat_message_types("BN_WHISPER"), []);
+        assert_eq!(wtf_group_to_chat_message_types("NOT_A_GROUP"), []);
+    }
+}